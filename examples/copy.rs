@@ -0,0 +1,90 @@
+use eframe::egui::{Context, Id, Modifiers};
+use eframe::{egui, App, Frame, NativeOptions};
+
+use egui_dnd::utils::shift_slice;
+use egui_dnd::{DragDropResponse, DragDropUi, DragableItem};
+
+struct DnDApp {
+    dnd: DragDropUi,
+    nodes: Vec<Node>,
+    next_id: usize,
+}
+
+impl Default for DnDApp {
+    fn default() -> Self {
+        let mut dnd = DragDropUi::default();
+        // dragging with alt or ctrl/cmd held copies the node instead of moving it
+        dnd.copy_modifiers = Modifiers::ALT | Modifiers::COMMAND;
+
+        let nodes = ["input", "blur", "sharpen", "output"]
+            .iter()
+            .enumerate()
+            .map(|(id, name)| Node {
+                id,
+                name: name.to_string(),
+            })
+            .collect::<Vec<_>>();
+        DnDApp {
+            dnd,
+            next_id: nodes.len(),
+            nodes,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Node {
+    // copies have the same name, so nodes are identified by an id instead
+    id: usize,
+    name: String,
+}
+
+impl DragableItem for Node {
+    fn drag_id(&self) -> Id {
+        Id::new(("node", self.id))
+    }
+}
+
+impl App for DnDApp {
+    fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.label("Hold alt or ctrl/cmd while dropping a node to copy it");
+            ui.add_space(8.0);
+
+            let response =
+                self.dnd
+                    .list_ui(ctx, ui, self.nodes.iter(), |ui, handle, _index, node| {
+                        ui.horizontal(|ui| {
+                            handle.ui(ui, node, |ui| {
+                                ui.label("grab");
+                            });
+                            ui.label(&node.name);
+                        });
+                    });
+
+            match response {
+                DragDropResponse::Completed(drag_indices) => {
+                    shift_slice(drag_indices.source, drag_indices.target, &mut self.nodes).unwrap();
+                }
+                DragDropResponse::CompletedCopy(drag_indices) => {
+                    let copy = Node {
+                        id: self.next_id,
+                        ..self.nodes[drag_indices.source].clone()
+                    };
+                    self.next_id += 1;
+                    self.nodes.insert(drag_indices.target, copy);
+                }
+                _ => {}
+            }
+        });
+    }
+}
+
+pub fn main() {
+    eframe::run_native(
+        "DnD Copy Example",
+        NativeOptions::default(),
+        Box::new(|_a| Ok(Box::new(DnDApp::default()))),
+    )
+    .unwrap();
+}
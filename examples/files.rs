@@ -0,0 +1,83 @@
+use eframe::egui::{Context, Id};
+use eframe::{egui, App, Frame, NativeOptions};
+
+use egui_dnd::utils::shift_slice;
+use egui_dnd::{DragDropResponse, DragDropUi, DragableItem};
+
+struct DnDApp {
+    dnd: DragDropUi,
+    files: Vec<FileItem>,
+    next_id: usize,
+}
+
+impl Default for DnDApp {
+    fn default() -> Self {
+        let mut dnd = DragDropUi::default();
+        // files dropped from the file manager are inserted where they hover
+        dnd.accept_files = true;
+
+        DnDApp {
+            dnd,
+            files: Vec::new(),
+            next_id: 0,
+        }
+    }
+}
+
+struct FileItem {
+    name: String,
+    // the same file can be dropped several times
+    id: usize,
+}
+
+impl DragableItem for FileItem {
+    fn drag_id(&self) -> Id {
+        Id::new(("file", self.id))
+    }
+}
+
+impl App for DnDApp {
+    fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.label("Drop files here:");
+
+            let response =
+                self.dnd
+                    .list_ui(ctx, ui, self.files.iter(), |ui, handle, _index, file| {
+                        ui.horizontal(|ui| {
+                            handle.ui(ui, file, |ui| {
+                                ui.label("grab");
+                            });
+                            ui.label(&file.name);
+                        });
+                    });
+
+            match response {
+                DragDropResponse::Completed(drag_indices) => {
+                    shift_slice(drag_indices.source, drag_indices.target, &mut self.files).unwrap();
+                }
+                DragDropResponse::CompletedFileDrop(index, dropped_files) => {
+                    for (offset, file) in dropped_files.into_iter().enumerate() {
+                        let name = file
+                            .path
+                            .map(|path| path.display().to_string())
+                            .unwrap_or(file.name);
+                        let id = self.next_id;
+                        self.next_id += 1;
+                        self.files.insert(index + offset, FileItem { name, id });
+                    }
+                }
+                _ => {}
+            }
+        });
+    }
+}
+
+pub fn main() {
+    eframe::run_native(
+        "DnD Files Example",
+        NativeOptions::default(),
+        Box::new(|_a| Ok(Box::new(DnDApp::default()))),
+    )
+    .unwrap();
+}
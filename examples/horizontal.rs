@@ -0,0 +1,97 @@
+use eframe::egui::{Context, Layout, Shape};
+use eframe::{egui, App, Frame, NativeOptions};
+
+use egui_dnd::utils::shift_slice;
+use egui_dnd::{DragDropResponse, DragDropUi};
+
+struct DnDApp {
+    // each list needs its own DragDropUi
+    left_to_right_dnd: DragDropUi,
+    left_to_right_items: Vec<String>,
+    right_to_left_dnd: DragDropUi,
+    right_to_left_items: Vec<String>,
+    wrapped_dnd: DragDropUi,
+    wrapped_items: Vec<String>,
+}
+
+impl Default for DnDApp {
+    fn default() -> Self {
+        // items are identified by their hash, so they must be unique across both lists
+        let to_strings = |names: &[&str]| names.iter().map(|name| name.to_string()).collect();
+
+        // the slot of the dragged thumbnail is outlined instead of showing a disabled copy of it
+        let mut wrapped_dnd = DragDropUi::default();
+        wrapped_dnd.set_placeholder_ui(|ui, _item, rect| {
+            let stroke = ui.visuals().widgets.noninteractive.fg_stroke;
+            let corners = [
+                rect.left_top(),
+                rect.right_top(),
+                rect.right_bottom(),
+                rect.left_bottom(),
+                rect.left_top(),
+            ];
+            ui.painter()
+                .extend(Shape::dashed_line(&corners, stroke, 4.0, 2.0));
+        });
+
+        DnDApp {
+            left_to_right_dnd: DragDropUi::default(),
+            left_to_right_items: to_strings(&["file", "edit", "view", "help"]),
+            right_to_left_dnd: DragDropUi::default(),
+            right_to_left_items: to_strings(&["back", "forward", "reload"]),
+            wrapped_dnd,
+            wrapped_items: (0..40).map(|i| format!("thumbnail {}", i)).collect(),
+        }
+    }
+}
+
+fn draw_list(ctx: &Context, ui: &mut egui::Ui, dnd: &mut DragDropUi, items: &mut [String]) {
+    let response = dnd.list_ui::<String>(ctx, ui, items.iter(), |ui, handle, _index, item| {
+        handle.ui(ui, item, |ui| {
+            ui.label(item);
+        });
+    });
+
+    if let DragDropResponse::Completed(drag_indices) = response {
+        shift_slice(drag_indices.source, drag_indices.target, items).unwrap();
+    }
+}
+
+impl App for DnDApp {
+    fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            // items are sorted along the main axis of the layout the list is drawn in
+            ui.horizontal(|ui| {
+                draw_list(
+                    ctx,
+                    ui,
+                    &mut self.left_to_right_dnd,
+                    &mut self.left_to_right_items,
+                );
+            });
+
+            ui.with_layout(Layout::right_to_left(egui::Align::Center), |ui| {
+                draw_list(
+                    ctx,
+                    ui,
+                    &mut self.right_to_left_dnd,
+                    &mut self.right_to_left_items,
+                );
+            });
+
+            // wrapping layouts are sorted row by row
+            ui.horizontal_wrapped(|ui| {
+                draw_list(ctx, ui, &mut self.wrapped_dnd, &mut self.wrapped_items);
+            });
+        });
+    }
+}
+
+pub fn main() {
+    eframe::run_native(
+        "DnD Horizontal Example",
+        NativeOptions::default(),
+        Box::new(|_a| Ok(Box::new(DnDApp::default()))),
+    )
+    .unwrap();
+}
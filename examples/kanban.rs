@@ -0,0 +1,112 @@
+use eframe::egui::{Context, Id};
+use eframe::{egui, App, Frame, NativeOptions};
+
+use egui_dnd::style::DragStyle;
+use egui_dnd::utils::{shift_slice, transfer_item};
+use egui_dnd::{DragDropResponse, DragDropUi};
+
+struct Column {
+    title: String,
+    // lists with an id can exchange items with each other
+    dnd: DragDropUi,
+    cards: Vec<String>,
+}
+
+impl Column {
+    fn new(title: &str, cards: &[&str]) -> Self {
+        Column {
+            title: title.to_string(),
+            dnd: DragDropUi::with_id(Id::new(title)),
+            cards: cards.iter().map(|card| card.to_string()).collect(),
+        }
+    }
+}
+
+struct DnDApp {
+    columns: Vec<Column>,
+}
+
+impl Default for DnDApp {
+    fn default() -> Self {
+        DnDApp {
+            columns: vec![
+                Column::new("todo", &["write docs", "fix bugs", "add tests"]),
+                Column::new("doing", &["horizontal lists"]),
+                Column::new("done", &[]),
+            ],
+        }
+    }
+}
+
+impl App for DnDApp {
+    fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let mut completed_transfer = None;
+
+            ui.columns(self.columns.len(), |uis| {
+                for (ui, column) in uis.iter_mut().zip(&mut self.columns) {
+                    ui.heading(&column.title);
+                    // dragged cards are lifted off the column
+                    column.dnd.drag_style = DragStyle::lifted(ui.style());
+                    let response = column.dnd.list_ui::<String>(
+                        ctx,
+                        ui,
+                        column.cards.iter(),
+                        |ui, handle, _index, card| {
+                            handle.ui(ui, card, |ui| {
+                                ui.label(card);
+                            });
+                        },
+                    );
+
+                    match response {
+                        DragDropResponse::Completed(drag_indices) => {
+                            shift_slice(
+                                drag_indices.source,
+                                drag_indices.target,
+                                &mut column.cards,
+                            )
+                            .unwrap();
+                        }
+                        // only the list the card was dropped into reports the transfer
+                        DragDropResponse::CompletedTransfer(transfer_indices) => {
+                            completed_transfer = Some(transfer_indices);
+                        }
+                        _ => {}
+                    }
+                }
+            });
+
+            // move the card once all columns are drawn
+            if let Some(transfer_indices) = completed_transfer {
+                let column_idx = |list_id| {
+                    self.columns
+                        .iter()
+                        .position(|column| column.dnd.id == Some(list_id))
+                        .unwrap()
+                };
+                let source_idx = column_idx(transfer_indices.source.list_id);
+                let target_idx = column_idx(transfer_indices.target.list_id);
+
+                let mut source_cards = std::mem::take(&mut self.columns[source_idx].cards);
+                transfer_item(
+                    transfer_indices.source.index,
+                    transfer_indices.target.index,
+                    &mut source_cards,
+                    &mut self.columns[target_idx].cards,
+                )
+                .unwrap();
+                self.columns[source_idx].cards = source_cards;
+            }
+        });
+    }
+}
+
+pub fn main() {
+    eframe::run_native(
+        "DnD Kanban Example",
+        NativeOptions::default(),
+        Box::new(|_a| Ok(Box::new(DnDApp::default()))),
+    )
+    .unwrap();
+}
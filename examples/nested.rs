@@ -0,0 +1,73 @@
+use eframe::egui::{Context, ScrollArea};
+use eframe::{egui, App, Frame, NativeOptions};
+
+use egui_dnd::utils::shift_slice;
+use egui_dnd::{dnd, DragDropResponse};
+
+struct Group {
+    name: String,
+    items: Vec<String>,
+}
+
+struct DnDApp {
+    // no DragDropUi needed, the state of each list is kept in egui memory
+    groups: Vec<Group>,
+}
+
+impl Default for DnDApp {
+    fn default() -> Self {
+        DnDApp {
+            groups: (0..3).map(new_group).collect(),
+        }
+    }
+}
+
+fn new_group(group: usize) -> Group {
+    Group {
+        name: format!("group {group}"),
+        items: (0..4).map(|i| format!("item {group}.{i}")).collect(),
+    }
+}
+
+impl App for DnDApp {
+    fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            if ui.button("add group").clicked() {
+                self.groups.push(new_group(self.groups.len()));
+            }
+
+            ScrollArea::vertical().show(ui, |ui| {
+                for group in &mut self.groups {
+                    ui.collapsing(&group.name, |ui| {
+                        // lists are created on the fly, identified by the group name
+                        let response = dnd(ui, &group.name).list_ui(
+                            group.items.iter(),
+                            |ui, handle, _index, item| {
+                                ui.horizontal(|ui| {
+                                    handle.ui(ui, item, |ui| {
+                                        ui.label("grab");
+                                    });
+                                    ui.label(item);
+                                });
+                            },
+                        );
+
+                        if let DragDropResponse::Completed(drag_indices) = response {
+                            shift_slice(drag_indices.source, drag_indices.target, &mut group.items)
+                                .unwrap();
+                        }
+                    });
+                }
+            });
+        });
+    }
+}
+
+pub fn main() {
+    eframe::run_native(
+        "DnD Nested Example",
+        NativeOptions::default(),
+        Box::new(|_a| Ok(Box::new(DnDApp::default()))),
+    )
+    .unwrap();
+}
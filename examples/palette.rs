@@ -0,0 +1,98 @@
+use eframe::egui::{Context, Id};
+use eframe::{egui, App, Frame, NativeOptions};
+
+use egui_dnd::utils::shift_slice;
+use egui_dnd::{DragDropResponse, DragDropUi, DragableItem};
+
+struct DnDApp {
+    palette_dnd: DragDropUi,
+    templates: Vec<&'static str>,
+    pipeline_dnd: DragDropUi,
+    steps: Vec<Step>,
+    next_id: usize,
+}
+
+impl Default for DnDApp {
+    fn default() -> Self {
+        // the palette only hands out copies of its templates
+        let mut palette_dnd = DragDropUi::with_id(Id::new("palette"));
+        palette_dnd.source_only = true;
+
+        DnDApp {
+            palette_dnd,
+            templates: vec!["blur", "sharpen", "resize", "rotate"],
+            pipeline_dnd: DragDropUi::with_id(Id::new("pipeline")),
+            steps: Vec::new(),
+            next_id: 0,
+        }
+    }
+}
+
+struct Step {
+    // a template can be added several times, so steps are identified by an id instead
+    id: usize,
+    name: &'static str,
+}
+
+impl DragableItem for Step {
+    fn drag_id(&self) -> Id {
+        Id::new(("step", self.id))
+    }
+}
+
+impl App for DnDApp {
+    fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+        egui::SidePanel::left("palette").show(ctx, |ui| {
+            ui.heading("Palette");
+            // dropping a template into the pipeline is reported by the pipeline list
+            self.palette_dnd.list_ui(
+                ctx,
+                ui,
+                self.templates.iter(),
+                |ui, handle, _index, template| {
+                    handle.ui(ui, template, |ui| {
+                        ui.label(*template);
+                    });
+                },
+            );
+        });
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading("Pipeline");
+            let response = self.pipeline_dnd.list_ui(
+                ctx,
+                ui,
+                self.steps.iter(),
+                |ui, handle, _index, step| {
+                    handle.ui(ui, step, |ui| {
+                        ui.label(step.name);
+                    });
+                },
+            );
+
+            match response {
+                DragDropResponse::Completed(drag_indices) => {
+                    shift_slice(drag_indices.source, drag_indices.target, &mut self.steps).unwrap();
+                }
+                DragDropResponse::CompletedTransferCopy(transfer_indices) => {
+                    let step = Step {
+                        id: self.next_id,
+                        name: self.templates[transfer_indices.source.index],
+                    };
+                    self.next_id += 1;
+                    self.steps.insert(transfer_indices.target.index, step);
+                }
+                _ => {}
+            }
+        });
+    }
+}
+
+pub fn main() {
+    eframe::run_native(
+        "DnD Palette Example",
+        NativeOptions::default(),
+        Box::new(|_a| Ok(Box::new(DnDApp::default()))),
+    )
+    .unwrap();
+}
@@ -0,0 +1,85 @@
+use eframe::egui::{Context, DragAndDrop, Frame, Id};
+use eframe::{egui, App, NativeOptions};
+
+use egui_dnd::utils::shift_slice;
+use egui_dnd::{DragDropResponse, DragDropUi};
+
+struct DnDApp {
+    dnd: DragDropUi,
+    items: Vec<String>,
+    added: usize,
+}
+
+impl Default for DnDApp {
+    fn default() -> Self {
+        let mut dnd = DragDropUi::default();
+        // strings dragged from the palette can be dropped into the list
+        dnd.accept_payload::<String>();
+
+        DnDApp {
+            dnd,
+            items: ["alfred", "bernhard", "christian"]
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+            added: 0,
+        }
+    }
+}
+
+impl App for DnDApp {
+    fn update(&mut self, ctx: &Context, _frame: &mut eframe::Frame) {
+        egui::SidePanel::left("palette").show(ctx, |ui| {
+            ui.label("Drag into the list:");
+            for fruit in ["apple", "banana", "cherry"] {
+                ui.dnd_drag_source(Id::new(fruit), fruit.to_string(), |ui| {
+                    ui.label(fruit);
+                });
+            }
+        });
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let response =
+                self.dnd
+                    .list_ui(ctx, ui, self.items.iter(), |ui, handle, index, item| {
+                        ui.horizontal(|ui| {
+                            // the index of the item is the payload, so the trash knows what to remove
+                            handle.with_payload(index).ui(ui, item, |ui| {
+                                ui.label("grab");
+                            });
+                            ui.label(item);
+                        });
+                    });
+
+            match response {
+                DragDropResponse::Completed(drag_indices) => {
+                    shift_slice(drag_indices.source, drag_indices.target, &mut self.items).unwrap();
+                }
+                DragDropResponse::CompletedPayloadDrop(index) => {
+                    if let Some(fruit) = DragAndDrop::take_payload::<String>(ctx) {
+                        self.added += 1;
+                        self.items.insert(index, format!("{fruit} {}", self.added));
+                    }
+                }
+                _ => {}
+            }
+
+            ui.add_space(16.0);
+            let (_, dropped) = ui.dnd_drop_zone::<usize, _>(Frame::group(ui.style()), |ui| {
+                ui.label("Drop here to remove");
+            });
+            if let Some(index) = dropped {
+                self.items.remove(*index);
+            }
+        });
+    }
+}
+
+pub fn main() {
+    eframe::run_native(
+        "DnD Payload Example",
+        NativeOptions::default(),
+        Box::new(|_a| Ok(Box::new(DnDApp::default()))),
+    )
+    .unwrap();
+}
@@ -39,9 +39,8 @@ impl App for DnDApp {
     fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
         egui::CentralPanel::default().show(ctx, |ui| {
             let response =
-                // make sure this is called in a vertical layout.
-                // Horizontal sorting is not supported yet.
-                self.dnd.list_ui::<ItemType>(ui, self.items.iter(), |ui, handle, index, item| {
+                // items are sorted along the main axis of the ui layout, see the horizontal example.
+                self.dnd.list_ui::<ItemType>(ctx, ui, self.items.iter(), |ui, handle, index, item| {
                     ui.horizontal(|ui| {
                         // Anything in the handle can be used to drag the item
                         handle.ui(ui, item, |ui| {
@@ -66,7 +65,7 @@ pub fn main() {
     eframe::run_native(
         "DnD Example",
         NativeOptions::default(),
-        Box::new(|_a| Box::new(DnDApp::default())),
+        Box::new(|_a| Ok(Box::new(DnDApp::default()))),
     )
     .unwrap();
 }
@@ -0,0 +1,72 @@
+use eframe::egui::{Context, Id, Vec2};
+use eframe::{egui, App, Frame, NativeOptions};
+
+use egui_dnd::utils::swap_slice;
+use egui_dnd::{DragDropResponse, DragDropUi, DragableItem, DropBehavior};
+
+struct DnDApp {
+    dnd: DragDropUi,
+    slots: Vec<Slot>,
+}
+
+impl Default for DnDApp {
+    fn default() -> Self {
+        let mut dnd = DragDropUi::default();
+        // dropping an item on another slot swaps the two, the other slots stay where they are
+        dnd.drop_behavior = DropBehavior::Swap;
+        // slots are dragged anywhere, without a drag handle
+        dnd.drag_whole_item = true;
+
+        let items = ["sword", "shield", "helmet", "boots", "potion"];
+        DnDApp {
+            dnd,
+            slots: (0..12)
+                .map(|id| Slot {
+                    id,
+                    item: items.get(id).map(|item| item.to_string()),
+                })
+                .collect(),
+        }
+    }
+}
+
+struct Slot {
+    // slots can be empty, so they are identified by an id instead of their item
+    id: usize,
+    item: Option<String>,
+}
+
+impl DragableItem for Slot {
+    fn drag_id(&self) -> Id {
+        Id::new(("slot", self.id))
+    }
+}
+
+impl App for DnDApp {
+    fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.set_max_width(300.0);
+            ui.horizontal_wrapped(|ui| {
+                let response =
+                    self.dnd
+                        .list_ui(ctx, ui, self.slots.iter(), |ui, _handle, _index, slot| {
+                            let text = slot.item.as_deref().unwrap_or("empty");
+                            ui.add_sized(Vec2::splat(64.0), egui::Label::new(text));
+                        });
+
+                if let DragDropResponse::Completed(drag_indices) = response {
+                    swap_slice(drag_indices.source, drag_indices.target, &mut self.slots).unwrap();
+                }
+            });
+        });
+    }
+}
+
+pub fn main() {
+    eframe::run_native(
+        "DnD Swap Example",
+        NativeOptions::default(),
+        Box::new(|_a| Ok(Box::new(DnDApp::default()))),
+    )
+    .unwrap();
+}
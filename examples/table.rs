@@ -0,0 +1,102 @@
+use eframe::egui::{Context, Id};
+use eframe::{egui, App, Frame, NativeOptions};
+use egui_extras::{Column, TableBuilder};
+
+use egui_dnd::utils::shift_slice;
+use egui_dnd::{DragDropResponse, DragDropUi, DragableItem};
+
+struct DnDApp {
+    dnd: DragDropUi,
+    tracks: Vec<Track>,
+}
+
+impl Default for DnDApp {
+    fn default() -> Self {
+        DnDApp {
+            dnd: DragDropUi::default(),
+            tracks: (0..1000)
+                .map(|id| Track {
+                    id,
+                    title: format!("Track {id}"),
+                    artist: ["Alfred", "Bernhard", "Christian"][id % 3].to_string(),
+                    length: 120 + (id * 37) % 180,
+                })
+                .collect(),
+        }
+    }
+}
+
+struct Track {
+    id: usize,
+    title: String,
+    artist: String,
+    length: usize,
+}
+
+impl DragableItem for Track {
+    fn drag_id(&self) -> Id {
+        Id::new(("track", self.id))
+    }
+}
+
+impl App for DnDApp {
+    fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let row_height = ui.spacing().interact_size.y;
+            TableBuilder::new(ui)
+                .striped(true)
+                .column(Column::exact(40.0))
+                .column(Column::remainder())
+                .column(Column::initial(120.0))
+                .column(Column::exact(60.0))
+                .header(row_height, |mut header| {
+                    header.col(|_ui| {});
+                    header.col(|ui| {
+                        ui.strong("Title");
+                    });
+                    header.col(|ui| {
+                        ui.strong("Artist");
+                    });
+                    header.col(|ui| {
+                        ui.strong("Length");
+                    });
+                })
+                .body(|body| {
+                    // the rows are dragged by the cells of the first column
+                    let response = self.dnd.table_ui(
+                        ctx,
+                        body,
+                        row_height,
+                        &self.tracks,
+                        0,
+                        |ui, index, column, track| {
+                            match column {
+                                0 => ui.label(format!("{}", index + 1)),
+                                1 => ui.label(&track.title),
+                                2 => ui.label(&track.artist),
+                                _ => ui.label(format!(
+                                    "{}:{:02}",
+                                    track.length / 60,
+                                    track.length % 60
+                                )),
+                            };
+                        },
+                    );
+
+                    if let DragDropResponse::Completed(drag_indices) = response {
+                        shift_slice(drag_indices.source, drag_indices.target, &mut self.tracks)
+                            .unwrap();
+                    }
+                });
+        });
+    }
+}
+
+pub fn main() {
+    eframe::run_native(
+        "DnD Table Example",
+        NativeOptions::default(),
+        Box::new(|_a| Ok(Box::new(DnDApp::default()))),
+    )
+    .unwrap();
+}
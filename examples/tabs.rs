@@ -0,0 +1,109 @@
+use eframe::egui::{Context, Id, Pos2};
+use eframe::{egui, App, Frame, NativeOptions};
+
+use egui_dnd::{DragableItem, TabBar};
+
+struct DnDApp {
+    tab_bar: TabBar,
+    tabs: Vec<Document>,
+    // tabs that were torn off, shown in their own windows
+    windows: Vec<(Document, Pos2)>,
+    next_id: usize,
+}
+
+impl Default for DnDApp {
+    fn default() -> Self {
+        let mut tab_bar = TabBar::new("documents");
+        tab_bar.tear_off = true;
+
+        DnDApp {
+            tab_bar,
+            tabs: (0..5).map(Document::new).collect(),
+            windows: Vec::new(),
+            next_id: 5,
+        }
+    }
+}
+
+struct Document {
+    id: usize,
+    title: String,
+}
+
+impl Document {
+    fn new(id: usize) -> Self {
+        Document {
+            id,
+            title: format!("Document {id}"),
+        }
+    }
+}
+
+impl DragableItem for Document {
+    fn drag_id(&self) -> Id {
+        Id::new(("document", self.id))
+    }
+}
+
+impl App for DnDApp {
+    fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+        egui::TopBottomPanel::top("tabs").show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui.button("+").on_hover_text("New tab").clicked() {
+                    let document = Document::new(self.next_id);
+                    self.next_id += 1;
+                    self.tab_bar.set_active(document.drag_id());
+                    self.tabs.push(document);
+                }
+
+                let response = self
+                    .tab_bar
+                    .ui(ui, &mut self.tabs, |document| document.title.clone());
+                if let Some(index) = response.closed {
+                    self.tabs.remove(index);
+                }
+                if let Some(torn_off) = response.torn_off {
+                    let document = self.tabs.remove(torn_off.index);
+                    self.windows.push((document, torn_off.pointer_pos));
+                }
+            });
+        });
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            match self.tab_bar.active_index(&self.tabs) {
+                Some(index) => ui.label(format!("Contents of {}", self.tabs[index].title)),
+                None => ui.label("No open tabs"),
+            };
+        });
+
+        // closing a window moves its document back into the tab bar
+        let mut reopened = Vec::new();
+        for (index, (document, pos)) in self.windows.iter().enumerate() {
+            let mut open = true;
+            egui::Window::new(&document.title)
+                .id(document.drag_id().with("window"))
+                .default_pos(*pos)
+                .open(&mut open)
+                .show(ctx, |ui| {
+                    ui.label(format!("Contents of {}", document.title));
+                });
+            if !open {
+                reopened.push(index);
+            }
+        }
+        for index in reopened.into_iter().rev() {
+            let (document, _) = self.windows.remove(index);
+            self.tab_bar.set_active(document.drag_id());
+            self.tabs.push(document);
+        }
+    }
+}
+
+pub fn main() {
+    eframe::run_native(
+        "DnD Tabs Example",
+        NativeOptions::default(),
+        Box::new(|_a| Ok(Box::new(DnDApp::default()))),
+    )
+    .unwrap();
+}
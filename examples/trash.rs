@@ -0,0 +1,73 @@
+use eframe::egui::Context;
+use eframe::{egui, App, Frame, NativeOptions};
+
+use egui_dnd::utils::shift_slice;
+use egui_dnd::{DragDropResponse, DragDropUi, DropZone};
+
+struct DnDApp {
+    dnd: DragDropUi,
+    items: Vec<String>,
+}
+
+impl Default for DnDApp {
+    fn default() -> Self {
+        let mut dnd = DragDropUi::default();
+        // items released outside of the list are removed
+        dnd.remove_on_drop_outside = true;
+
+        DnDApp {
+            dnd,
+            items: ["alfred", "bernhard", "christian", "david", "emil"]
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl App for DnDApp {
+    fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.label("Drag items out of the list or onto the trash to remove them");
+            ui.add_space(8.0);
+
+            let response =
+                self.dnd
+                    .list_ui(ctx, ui, self.items.iter(), |ui, handle, _index, item| {
+                        ui.horizontal(|ui| {
+                            handle.ui(ui, item, |ui| {
+                                ui.label("grab");
+                            });
+                            ui.label(item);
+                        });
+                    });
+
+            match response {
+                DragDropResponse::Completed(drag_indices) => {
+                    shift_slice(drag_indices.source, drag_indices.target, &mut self.items).unwrap();
+                }
+                DragDropResponse::Removed(index) => {
+                    self.items.remove(index);
+                }
+                _ => {}
+            }
+
+            ui.add_space(16.0);
+            let (_, dropped) = DropZone::new("trash").show(ui, |ui| {
+                ui.label("Trash");
+            });
+            if let Some(dropped) = dropped {
+                self.items.remove(dropped.index);
+            }
+        });
+    }
+}
+
+pub fn main() {
+    eframe::run_native(
+        "DnD Trash Example",
+        NativeOptions::default(),
+        Box::new(|_a| Ok(Box::new(DnDApp::default()))),
+    )
+    .unwrap();
+}
@@ -0,0 +1,98 @@
+use eframe::egui::{Context, Id};
+use eframe::{egui, App, Frame, NativeOptions};
+
+use egui_dnd::tree::{DragableTreeItem, TreeDragDropResponse};
+use egui_dnd::utils::move_tree_item;
+use egui_dnd::{DragDropUi, DragableItem};
+
+struct SortableItem {
+    name: String,
+    // items without children are leaves, other items can't be dropped into them
+    children: Option<Vec<SortableItem>>,
+}
+
+impl SortableItem {
+    fn leaf(name: &str) -> Self {
+        SortableItem {
+            name: name.to_string(),
+            children: None,
+        }
+    }
+
+    fn folder(name: &str, children: Vec<SortableItem>) -> Self {
+        SortableItem {
+            name: name.to_string(),
+            children: Some(children),
+        }
+    }
+}
+
+impl DragableItem for SortableItem {
+    fn drag_id(&self) -> Id {
+        Id::new(&self.name)
+    }
+}
+
+impl DragableTreeItem for SortableItem {
+    fn children(&self) -> Option<&[Self]> {
+        self.children.as_deref()
+    }
+
+    fn children_mut(&mut self) -> Option<&mut Vec<Self>> {
+        self.children.as_mut()
+    }
+}
+
+struct DnDApp {
+    dnd: DragDropUi,
+    items: Vec<SortableItem>,
+}
+
+impl Default for DnDApp {
+    fn default() -> Self {
+        DnDApp {
+            dnd: DragDropUi::default(),
+            items: vec![
+                SortableItem::leaf("a"),
+                SortableItem::leaf("b"),
+                SortableItem::folder(
+                    "c",
+                    vec![
+                        SortableItem::leaf("c_a"),
+                        SortableItem::folder("c_b", vec![SortableItem::leaf("c_b_a")]),
+                    ],
+                ),
+                SortableItem::folder("d", vec![]),
+            ],
+        }
+    }
+}
+
+impl App for DnDApp {
+    fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let response = self
+                .dnd
+                .tree_ui(ctx, ui, &self.items, |ui, handle, _path, item| {
+                    handle.ui(ui, item, |ui| {
+                        ui.label(&item.name);
+                    });
+                });
+
+            // the paths returned can be used with move_tree_item if the tree is stored in Vecs
+            if let TreeDragDropResponse::Completed(drag_indices) = response {
+                move_tree_item(&drag_indices.source, &drag_indices.target, &mut self.items)
+                    .unwrap();
+            }
+        });
+    }
+}
+
+pub fn main() {
+    eframe::run_native(
+        "DnD Tree Example",
+        NativeOptions::default(),
+        Box::new(|_a| Ok(Box::new(DnDApp::default()))),
+    )
+    .unwrap();
+}
@@ -0,0 +1,75 @@
+use eframe::egui::{Context, ScrollArea};
+use eframe::{egui, App, Frame, NativeOptions};
+
+use egui_dnd::style::DropIndicator;
+use egui_dnd::utils::shift_slice;
+use egui_dnd::{DragDropResponse, DragDropUi};
+
+struct DnDApp {
+    dnd: DragDropUi,
+    items: Vec<String>,
+}
+
+impl Default for DnDApp {
+    fn default() -> Self {
+        let mut dnd = DragDropUi::default();
+        // the rows stay in place and a line shows where the dragged row will be inserted
+        dnd.drop_indicator = DropIndicator::Line { dim_dragged: true };
+        // rows are dragged after holding them for a moment, so that swiping over them scrolls the
+        // list on touch screens
+        dnd.drag_delay = 0.3;
+        dnd.drag_threshold = 8.0;
+
+        DnDApp {
+            dnd,
+            items: (0..50_000).map(|i| format!("entry {i}")).collect(),
+        }
+    }
+}
+
+impl App for DnDApp {
+    fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            // every tenth entry has a second line, so its row is twice as tall. the heights of all
+            // rows are needed to position the visible ones, so they should be cheap to get.
+            let row_height = ui.spacing().interact_size.y;
+            let lines = |item: &String| if item.ends_with('0') { 2.0 } else { 1.0 };
+
+            // only the visible rows are drawn. drag an item to the edge of the scroll area to
+            // scroll it.
+            let response = self.dnd.virtual_list_ui_with_heights(
+                ctx,
+                ui,
+                ScrollArea::vertical().auto_shrink(false),
+                |_index, item| lines(item) * row_height,
+                &self.items,
+                |ui, handle, _index, item| {
+                    ui.horizontal(|ui| {
+                        ui.set_height(lines(item) * row_height);
+                        handle.ui(ui, item, |ui| {
+                            ui.label("grab");
+                        });
+                        if lines(item) > 1.0 {
+                            ui.label(format!("{item}\nwith a second line"));
+                        } else {
+                            ui.label(item);
+                        }
+                    });
+                },
+            );
+
+            if let DragDropResponse::Completed(drag_indices) = response {
+                shift_slice(drag_indices.source, drag_indices.target, &mut self.items).unwrap();
+            }
+        });
+    }
+}
+
+pub fn main() {
+    eframe::run_native(
+        "DnD Virtual List Example",
+        NativeOptions::default(),
+        Box::new(|_a| Ok(Box::new(DnDApp::default()))),
+    )
+    .unwrap();
+}
@@ -0,0 +1,114 @@
+use crate::{
+    handle::DragHandle,
+    tree::{DragableTreeItem, TreeDragDropResponse},
+    DragDropResponse, DragDropUi, DragableItem,
+};
+use egui::{self, Id, ScrollArea, Ui};
+use std::hash::Hash;
+
+/// Starts a list whose [`DragDropUi`] is kept in egui memory under `id` instead of being owned by
+/// the app, so that lists can be created on the fly, e.g. for each group of a nested list:
+///
+/// ```rust,no_run
+/// # use egui_dnd::{dnd, DragDropResponse, utils::shift_slice};
+/// # fn group_ui(ui: &mut egui::Ui, group: &str, items: &mut Vec<String>) {
+/// let response = dnd(ui, group).list_ui(items.iter(), |ui, handle, _index, item| {
+///     handle.ui(ui, item, |ui| {
+///         ui.label(item.as_str());
+///     });
+/// });
+/// if let DragDropResponse::Completed(drag_indices) = response {
+///     shift_slice(drag_indices.source, drag_indices.target, items).unwrap();
+/// }
+/// # }
+/// ```
+pub fn dnd(ui: &mut Ui, id: impl Hash) -> Dnd<'_> {
+    let id = Id::new(id).with("egui_dnd");
+    let state = ui
+        .data_mut(|d| d.get_temp::<DragDropUi>(id))
+        .unwrap_or_default();
+    Dnd { ui, id, state }
+}
+
+/// A list with its [`DragDropUi`] loaded from egui memory, see [`dnd`]. Drawing the list stores the
+/// state again.
+pub struct Dnd<'a> {
+    ui: &'a mut Ui,
+    id: Id,
+    state: DragDropUi,
+}
+
+impl<'a> Dnd<'a> {
+    /// Changes the settings of the list, e.g. `dnd(ui, id).configure(|dnd| dnd.multi_select = true)`.
+    /// The settings are kept in memory along with the dragging state.
+    pub fn configure(mut self, configure: impl FnOnce(&mut DragDropUi)) -> Self {
+        configure(&mut self.state);
+        self
+    }
+
+    /// Draws the list like [`DragDropUi::list_ui`]
+    pub fn list_ui<'b, T: DragableItem + 'b>(
+        self,
+        items: impl Iterator<Item = &'b T>,
+        item_ui: impl FnMut(&mut Ui, DragHandle, usize, &T),
+    ) -> DragDropResponse {
+        self.show(|state, ui| {
+            let context = ui.ctx().clone();
+            state.list_ui(&context, ui, items, item_ui)
+        })
+    }
+
+    /// Draws the tree like [`DragDropUi::tree_ui`]
+    pub fn tree_ui<T: DragableTreeItem>(
+        self,
+        roots: &[T],
+        item_ui: impl FnMut(&mut Ui, DragHandle, &[usize], &T),
+    ) -> TreeDragDropResponse {
+        self.show(|state, ui| {
+            let context = ui.ctx().clone();
+            state.tree_ui(&context, ui, roots, item_ui)
+        })
+    }
+
+    /// Draws the list like [`DragDropUi::virtual_list_ui`]
+    pub fn virtual_list_ui<T: DragableItem>(
+        self,
+        scroll_area: ScrollArea,
+        row_height: f32,
+        items: &[T],
+        item_ui: impl FnMut(&mut Ui, DragHandle, usize, &T),
+    ) -> DragDropResponse {
+        self.show(|state, ui| {
+            let context = ui.ctx().clone();
+            state.virtual_list_ui(&context, ui, scroll_area, row_height, items, item_ui)
+        })
+    }
+
+    /// Draws the list like [`DragDropUi::virtual_list_ui_with_heights`]
+    pub fn virtual_list_ui_with_heights<T: DragableItem>(
+        self,
+        scroll_area: ScrollArea,
+        row_height: impl Fn(usize, &T) -> f32,
+        items: &[T],
+        item_ui: impl FnMut(&mut Ui, DragHandle, usize, &T),
+    ) -> DragDropResponse {
+        self.show(|state, ui| {
+            let context = ui.ctx().clone();
+            state.virtual_list_ui_with_heights(
+                &context,
+                ui,
+                scroll_area,
+                row_height,
+                items,
+                item_ui,
+            )
+        })
+    }
+
+    /// Draws the list with the loaded state and stores the state again
+    fn show<R>(mut self, add_contents: impl FnOnce(&mut DragDropUi, &mut Ui) -> R) -> R {
+        let response = add_contents(&mut self.state, self.ui);
+        self.ui.data_mut(|d| d.insert_temp(self.id, self.state));
+        response
+    }
+}
@@ -0,0 +1,163 @@
+use crate::DragDropUi;
+use egui::{self, Context, Frame, Id, InnerResponse, Key, Ui};
+use std::hash::Hash;
+
+/// An item of a [`DragDropUi`] list that was dropped on a [`DropZone`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DroppedItem {
+    /// [`DragDropUi::id`] of the list the item was dragged from, if it has one
+    pub list_id: Option<Id>,
+    /// Index of the item in its list
+    pub index: usize,
+    /// [`crate::DragableItem::drag_id`] of the item
+    pub item_id: Id,
+}
+
+/// An area that list items can be dropped on, e.g. a trash can. It is highlighted while an item of
+/// any [`DragDropUi`] list hovers over it, and reports the item once it is dropped. The list the
+/// item was dragged from returns [`crate::DragDropResponse::NoDrag`], like when an item is dropped
+/// into another list.
+///
+/// ```rust,no_run
+/// # use egui_dnd::DropZone;
+/// # fn trash_ui(ui: &mut egui::Ui, items: &mut Vec<String>) {
+/// let (_, dropped) = DropZone::new("trash").show(ui, |ui| {
+///     ui.label("Drop here to remove");
+/// });
+/// if let Some(dropped) = dropped {
+///     items.remove(dropped.index);
+/// }
+/// # }
+/// ```
+pub struct DropZone {
+    id: Id,
+    frame: Option<Frame>,
+}
+
+impl DropZone {
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt).with("egui_dnd_drop_zone"),
+            frame: None,
+        }
+    }
+
+    /// Frame drawn around the contents. Its fill and stroke are replaced by the style of
+    /// [`egui::style::Widgets::inactive`], or [`egui::style::Widgets::active`] while an item hovers
+    /// over the zone. Defaults to [`Frame::group`].
+    pub fn frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// Draws the zone with `add_contents` inside. Returns the item dropped on it this frame, if any.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> (InnerResponse<R>, Option<DroppedItem>) {
+        let dragged = DraggedItem::load(ui.ctx())
+            .filter(|dragged| ui.ctx().dragged_id() == Some(dragged.item.item_id));
+
+        let frame = self.frame.unwrap_or_else(|| Frame::group(ui.style()));
+        let mut frame = frame.begin(ui);
+        let inner = add_contents(&mut frame.content_ui);
+        let response = frame.allocate_space(ui);
+
+        // `hovered` is always false while something is dragged
+        let hovered = response.contains_pointer();
+        let style = if dragged.is_some() && hovered {
+            ui.visuals().widgets.active
+        } else {
+            ui.visuals().widgets.inactive
+        };
+        frame.frame.fill = style.bg_fill;
+        frame.frame.stroke = style.bg_stroke;
+        frame.paint(ui);
+
+        // the item hovering over this zone. the item isn't dragged anymore once it is released, so
+        // it is kept in memory.
+        let hovering_item = ui.data(|d| d.get_temp::<DroppedItem>(self.id));
+        if let Some(mut dragged) = dragged {
+            if hovered {
+                ui.data_mut(|d| d.insert_temp(self.id, dragged.item));
+            } else if hovering_item.is_some() {
+                ui.data_mut(|d| d.remove::<DroppedItem>(self.id));
+            }
+
+            // let the list know whether the item is about to be dropped here
+            if hovered || dragged.zone == Some(self.id) {
+                dragged.zone = hovered.then_some(self.id);
+                dragged.store(ui.ctx());
+            }
+        } else if hovering_item.is_some() {
+            ui.data_mut(|d| d.remove::<DroppedItem>(self.id));
+        }
+
+        let released = ui.input(|i| i.pointer.any_released() && !i.key_pressed(Key::Escape));
+        // the list decides whether the item was dropped here from the previous frames as well
+        let dropped = hovering_item.filter(|_| dragged.is_none() && released);
+        (InnerResponse::new(inner, response), dropped)
+    }
+}
+
+/// The item currently dragged with the pointer in any list, shared with the drop zones via egui
+/// memory.
+#[derive(Clone, Copy)]
+struct DraggedItem {
+    item: DroppedItem,
+    /// The drop zone the item would be dropped on if released now
+    zone: Option<Id>,
+}
+
+impl DraggedItem {
+    /// Id the current [`DraggedItem`] is stored under in egui memory
+    fn memory_id() -> Id {
+        Id::new("egui_dnd_dragged_item")
+    }
+
+    fn load(context: &Context) -> Option<Self> {
+        context.data(|d| d.get_temp(Self::memory_id()))
+    }
+
+    fn store(self, context: &Context) {
+        context.data_mut(|d| d.insert_temp(Self::memory_id(), self));
+    }
+
+    fn clear(context: &Context) {
+        context.data_mut(|d| d.remove::<Self>(Self::memory_id()));
+    }
+}
+
+impl DragDropUi {
+    /// Shares the item `item_id` at `index` that is dragged with the pointer with the drop zones.
+    pub(crate) fn share_with_drop_zones(&self, context: &Context, index: usize, item_id: Id) {
+        let zone = DraggedItem::load(context)
+            .filter(|dragged| dragged.item.item_id == item_id)
+            .and_then(|dragged| dragged.zone);
+        DraggedItem {
+            item: DroppedItem {
+                list_id: self.id,
+                index,
+                item_id,
+            },
+            zone,
+        }
+        .store(context);
+    }
+
+    /// Whether the item at `index` is hovering over a drop zone. `released` clears the shared item,
+    /// since dragging it ended.
+    pub(crate) fn over_drop_zone(&self, context: &Context, index: usize, released: bool) -> bool {
+        let Some(dragged) = DraggedItem::load(context) else {
+            return false;
+        };
+        let over_zone = dragged.item.list_id == self.id
+            && dragged.item.index == index
+            && dragged.zone.is_some();
+        if released {
+            DraggedItem::clear(context);
+        }
+        over_zone
+    }
+}
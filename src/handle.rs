@@ -1,39 +1,104 @@
 use crate::{DragDropUi, DragableItem};
-use egui::{self, CursorIcon, Pos2, Sense, Ui};
+use egui::{self, Context, CursorIcon, DragAndDrop, Id, PointerButton, Response, Sense, Ui};
+use std::any::Any;
+
+type SetPayload = dyn FnOnce(&Context);
 
 /// [Handle::ui] is used to draw the drag handle
 pub struct DragHandle<'a> {
     pub state: &'a mut DragDropUi,
     pub placeholder: bool,
+    /// Whether the item can be dragged, see [`DragDropUi::set_can_drag`]
+    pub draggable: bool,
+    /// Sets the egui drag and drop payload while the item is dragged, see [`DragHandle::with_payload`]
+    pub(crate) payload: Option<Box<SetPayload>>,
 }
 
 /// The part of the item ui thats draggable. Accessible by the user with the `item_ui` parameter of [`DragDropUi::ui`]
 impl<'a> DragHandle<'a> {
+    /// Sets `payload` as egui's drag and drop payload while the item is dragged, so that other
+    /// widgets can accept the item, e.g. with [`Response::dnd_release_payload`] or another list's
+    /// [`DragDropUi::accept_payload`]. If the item is dropped outside of its list, the list reports
+    /// [`crate::DragDropResponse::Cancelled`].
+    pub fn with_payload<P: Any + Send + Sync>(mut self, payload: P) -> Self {
+        self.payload = Some(Box::new(move |ctx: &Context| {
+            DragAndDrop::set_payload(ctx, payload);
+        }));
+        self
+    }
+
     pub fn ui<T: DragableItem>(self, ui: &mut Ui, item: &T, contents: impl FnOnce(&mut Ui)) {
-        if self.placeholder {
-            // if this is meant to be a placeholder ui, dont do the draggable stuff.
+        if self.placeholder || !self.draggable {
+            // if this is meant to be a placeholder ui or the item can't be dragged, dont do the
+            // draggable stuff.
+            contents(ui);
+            return;
+        }
+        if self.state.drag_whole_item {
+            // the whole item is dragged instead, see `DragDropUi::draw_item`
             contents(ui);
+            self.set_payload(ui.ctx(), item.drag_id());
             return;
         }
 
         // add contents to ui
         let added_contents = ui.scope(contents);
-        let dragable_response =
-            ui.interact(added_contents.response.rect, item.drag_id(), Sense::drag());
-
-        // if pointer hovering above this widget, update pointer icon
-        if dragable_response.hovered() {
-            ui.ctx().set_cursor_icon(CursorIcon::Grab);
+        // with multi select enabled, the handle is also clicked to select the item. delayed drags
+        // are started in `DragDropUi::start_delayed_drag` instead of by egui.
+        let sense = if self.state.delays_drag() {
+            Sense::click()
+        } else if self.state.multi_select {
+            Sense::click_and_drag()
+        } else {
+            Sense::drag()
+        };
+        // focusable so that the item can be picked up with the keyboard
+        let sense = Sense {
+            focusable: true,
+            ..sense
+        };
+        let dragable_response = ui.interact(added_contents.response.rect, item.drag_id(), sense);
+        let focused = dragable_response.has_focus();
+        show_drag_area(self.state, ui, item.drag_id(), &dragable_response, focused);
+        if self.state.delays_drag() {
+            self.state
+                .start_delayed_drag(ui, item.drag_id(), &dragable_response);
         }
 
-        // if dragging this widget just began, store the intial pointer position relative to the widget origin
-        if dragable_response.drag_started() {
-            let top_left = added_contents.response.rect.min.to_vec2();
-            let pointer_pos = dragable_response
-                .interact_pointer_pos()
-                .unwrap_or(Pos2::default())
-                .to_vec2();
-            self.state.drag_delta = Some(top_left - pointer_pos);
+        self.set_payload(ui.ctx(), item.drag_id());
+    }
+
+    /// Sets the payload of [`DragHandle::with_payload`] while the item is dragged
+    fn set_payload(self, ctx: &Context, id: Id) {
+        if let (Some(set_payload), true) = (self.payload, ctx.is_being_dragged(id)) {
+            set_payload(ctx);
         }
     }
 }
+
+/// Shows that `response` can be dragged and that the item `id` has keyboard focus if `focused` is
+/// set. Pointer clicks select the item if [`DragDropUi::multi_select`] is enabled.
+pub(crate) fn show_drag_area(
+    state: &mut DragDropUi,
+    ui: &Ui,
+    id: Id,
+    response: &Response,
+    focused: bool,
+) {
+    // if pointer hovering above this widget, update pointer icon
+    if response.hovered() {
+        ui.ctx().set_cursor_icon(CursorIcon::Grab);
+    }
+
+    if focused {
+        let rounding = ui.visuals().widgets.active.rounding;
+        ui.painter()
+            .rect_stroke(response.rect, rounding, ui.visuals().selection.stroke);
+    }
+
+    // space and enter also click the focused handle, but they pick up and drop the item instead,
+    // see `DragDropUi::update_keyboard_drag`
+    if response.clicked_by(PointerButton::Primary) && state.multi_select {
+        state.clicked_item = Some(id);
+    }
+}
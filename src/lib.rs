@@ -1,11 +1,34 @@
+mod dnd;
+mod drop_zone;
 pub mod handle;
+pub mod style;
+mod tab_bar;
+#[cfg(feature = "egui_extras")]
+mod table;
+pub mod tree;
 pub mod utils;
+mod virtual_list;
 
-use egui::{self, Context, CursorIcon, Id, LayerId, Order, Rect, Sense, Shape, Ui, Vec2};
-use epaint::TextureId;
+pub use dnd::{dnd, Dnd};
+pub use drop_zone::{DropZone, DroppedItem};
+pub use tab_bar::{TabBar, TabBarResponse, TornOffTab};
+
+use egui::{
+    self,
+    emath::{Rot2, TSTransform},
+    layers::ShapeIdx,
+    style::ScrollAnimation,
+    Context, CursorIcon, Direction, DragAndDrop, DroppedFile, EventFilter, Id, InnerResponse, Key,
+    LayerId, Modifiers, Order, Pos2, Rect, Response, Sense, Shape, Stroke, Ui, UiBuilder, Vec2,
+};
+use epaint::{Mesh, Tessellator, TextureId};
 use handle::DragHandle;
+use std::any::Any;
 use std::hash::Hash;
-use utils::shift_slice;
+use std::sync::Arc;
+use style::{DragStyle, DropIndicator};
+use tree::TreeDragIndices;
+use utils::{shift_slice, shift_slice_many, swap_slice};
 
 #[derive(Default, Clone, Copy)]
 pub struct DragIndices {
@@ -13,11 +36,98 @@ pub struct DragIndices {
     pub target: usize,
 }
 
+/// What happens to the other items when an item is dropped, see [`DragDropUi::drop_behavior`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DropBehavior {
+    /// The dragged item is inserted in front of the item at [`DragIndices::target`], shifting the
+    /// items between the source and the target, see [`utils::shift_slice`]
+    #[default]
+    Insert,
+    /// The dragged item trades places with the item at [`DragIndices::target`], the items between
+    /// them stay where they are, see [`utils::swap_slice`]
+    Swap,
+}
+
+/// Position of an item in one of several lists that items can be dragged between.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ListIndex {
+    /// [`DragDropUi::id`] of the list
+    pub list_id: Id,
+    pub index: usize,
+}
+
+/// The dragged item a custom placeholder is drawn for, see [`DragDropUi::set_placeholder_ui`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlaceholderItem {
+    /// An item of this list at `index`, with its [`DragableItem::drag_id`]
+    Item { index: usize, item_id: Id },
+    /// An item dragged in from the list and index `source`, see [`DragDropUi::id`]
+    Transfer { source: ListIndex, item_id: Id },
+    /// An egui drag and drop payload, see [`DragDropUi::accept_payload`]
+    Payload,
+    /// Files dragged in from outside the app, see [`DragDropUi::accept_files`]
+    Files,
+}
+
+/// Source and target of an item dragged from one list to another. `target.index` is the index the
+/// item should be inserted at in the target list, see [`utils::transfer_item`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TransferIndices {
+    pub source: ListIndex,
+    pub target: ListIndex,
+}
+
+/// Source and target of several selected items dragged at once, see [`DragDropUi::multi_select`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MultiDragIndices {
+    /// Indices of the dragged items, in ascending order
+    pub sources: Vec<usize>,
+    /// Index the items should be moved to as a block, see [`utils::shift_slice_many`]. `None` if
+    /// the items aren't hovering over the list, in which case releasing them cancels the drag.
+    pub target: Option<usize>,
+}
+
 #[derive(Clone)]
 pub enum DragDropResponse {
     NoDrag,
     CurrentDrag(DragIndices),
     Completed(DragIndices),
+    /// The drag was cancelled by pressing escape or releasing the item outside of any list. The
+    /// item stays at `source`, `target` is where it was hovering when the drag was cancelled.
+    Cancelled(DragIndices),
+    /// An item from another list is being dragged over this list. Only returned by the list being
+    /// hovered over.
+    CurrentTransfer(TransferIndices),
+    /// An item from another list was dropped into this list. Only returned by the target list, the
+    /// source list returns [`DragDropResponse::NoDrag`].
+    CompletedTransfer(TransferIndices),
+    /// A copy of an item from another list was dropped into this list, because the other list is
+    /// [`DragDropUi::source_only`] or one of its [`DragDropUi::copy_modifiers`] was held. Like
+    /// [`DragDropResponse::CompletedTransfer`], but the item stays in the other list and a copy
+    /// of it should be inserted into this one.
+    CompletedTransferCopy(TransferIndices),
+    /// Several selected items are being dragged, see [`DragDropUi::multi_select`]
+    CurrentMultiDrag(MultiDragIndices),
+    /// Several selected items were dropped, see [`DragDropUi::multi_select`]
+    CompletedMultiDrag(MultiDragIndices),
+    /// An egui drag and drop payload accepted by this list is hovering over it, see
+    /// [`DragDropUi::accept_payload`]. Contains the index the payload would be inserted at.
+    CurrentPayloadDrop(usize),
+    /// An egui drag and drop payload accepted by this list was dropped at the index, see
+    /// [`DragDropUi::accept_payload`]. Take the payload with [`DragAndDrop::take_payload`].
+    CompletedPayloadDrop(usize),
+    /// Files from outside the app are hovering over the list, see [`DragDropUi::accept_files`].
+    /// Contains the index the files would be inserted at.
+    CurrentFileDrop(usize),
+    /// Files from outside the app were dropped at the index, see [`DragDropUi::accept_files`]
+    CompletedFileDrop(usize, Vec<DroppedFile>),
+    /// The item at the index was released outside of the list and should be removed, see
+    /// [`DragDropUi::remove_on_drop_outside`]
+    Removed(usize),
+    /// The item at `source` was dropped while a copy modifier was held, see
+    /// [`DragDropUi::copy_modifiers`]. A copy of it should be inserted at `target`, which is an
+    /// index of the list before the copy is added, and the item stays where it is.
+    CompletedCopy(DragIndices),
 }
 
 pub trait DragableItem {
@@ -39,16 +149,21 @@ impl<T: Hash> DragableItem for T {
 /// - a reference to the current item in the `items` list
 ///
 /// # Example
-/// ```rust
+/// ```rust,no_run
+/// use eframe::egui::{CentralPanel, Context};
+/// use eframe::{App, Frame, NativeOptions};
+/// use egui_dnd::utils::shift_slice;
+/// use egui_dnd::{DragDropResponse, DragDropUi};
+///
 /// struct DnDApp {
 ///     items: Vec<String>,
 ///     dnd: DragDropUi,
 /// }
 ///
 /// impl App for DnDApp {
-///     fn update(&mut self, ctx: &Context, frame: &mut Frame) {
+///     fn update(&mut self, ctx: &Context, _frame: &mut Frame) {
 ///         CentralPanel::default().show(ctx, |ui| {
-///             let response = self.dnd.ui(ui, self.items.iter(), |ui, handle, _index, item| {
+///             let response = self.dnd.list_ui(ctx, ui, self.items.iter(), |ui, handle, _index, item| {
 ///                 ui.horizontal(|ui| {
 ///                     handle.ui(ui, item, |ui| {
 ///                         ui.label("grab");
@@ -57,21 +172,20 @@ impl<T: Hash> DragableItem for T {
 ///                 });
 ///             });
 ///             if let DragDropResponse::Completed(drag_indices) = response {
-///                 shift_vec(drag_indices.source, drag_indices.target, &mut self.items);
+///                 shift_slice(drag_indices.source, drag_indices.target, &mut self.items).unwrap();
 ///             }
 ///         });
 ///     }
 /// }
 ///
 /// pub fn main() {
-///     use eframe::NativeOptions;
-///     let dnd = DragDropUi::default();
 ///     eframe::run_native("DnD Example", NativeOptions::default(), Box::new(|_| {
-///         Box::new(DnDApp {
+///         Ok(Box::new(DnDApp {
 ///             dnd: DragDropUi::default(),
 ///             items: vec!["a", "b", "c"].into_iter().map(|s| s.to_string()).collect(),
-///         })
-///     }));
+///         }))
+///     }))
+///     .unwrap();
 /// }
 /// ```
 #[derive(Clone)]
@@ -79,12 +193,233 @@ pub struct DragDropUi {
     drag_indices: Option<DragIndices>,
     /// Pointer position relative to the origin of the dragged widget when dragging began
     drag_delta: Option<Vec2>,
+    /// Item from another list currently hovering over this list
+    incoming: Option<Transfer>,
+    /// Source and target of the node being dragged in [`DragDropUi::tree_ui`]
+    tree_drag: Option<TreeDragIndices>,
+    /// Sources and target when dragging several selected items at once
+    multi_drag: Option<MultiDragIndices>,
+    /// Ids of the selected items
+    selection: Vec<Id>,
+    /// Item that shift-click selection ranges start from
+    selection_anchor: Option<Id>,
+    /// Item whose drag handle was clicked this frame
+    clicked_item: Option<Id>,
+    /// Item picked up with the keyboard, see [`DragDropUi::list_ui`]
+    keyboard_drag: Option<Id>,
+    /// Item whose drag handle is held down before it is dragged, see [`DragDropUi::drag_delay`]
+    held_item: Option<HeldItem>,
+    /// Time of the last frame an item was dragged over this list
+    last_drag_time: f64,
+    /// Whether items can be dragged, see [`DragDropUi::set_can_drag`]
+    can_drag: Option<Arc<CanDrag>>,
+    /// Whether items can be dropped at a target, see [`DragDropUi::set_can_drop`]
+    can_drop: Option<Arc<CanDrop>>,
+    /// Whether the dragged item hovers over a target it can't be dropped at
+    drop_not_allowed: bool,
+    /// Whether releasing the dragged item would remove it, see
+    /// [`DragDropUi::remove_on_drop_outside`]
+    drop_removes: bool,
+    /// Whether the dragged item would be copied if dropped now, see [`DragDropUi::copy_modifiers`]
+    copying: bool,
+    /// Size of the dragged item while a copy of it is previewed at its target. The item stays in
+    /// place and an empty slot of this size is shown where the copy will be inserted.
+    copy_preview: Option<Vec2>,
+    /// Draws the slot of the dragged item, see [`DragDropUi::set_placeholder_ui`]
+    placeholder_ui: Option<Arc<PlaceholderUi>>,
+    /// Checks for an egui drag and drop payload this list accepts, see [`DragDropUi::accept_payload`]
+    has_accepted_payload: Option<fn(&Context) -> bool>,
+    /// What is hovering over this list other than a list item, see [`ForeignDrop`]
+    foreign_drop: Option<ForeignDrop>,
     pub draw_drop_preview: bool,
+    /// Identifies this list when dragging items between lists. Lists with an id can exchange items
+    /// with any other list that has an id, lists without one only sort their own items.
+    pub id: Option<Id>,
+    /// Allows selecting items by clicking their drag handles: ctrl/cmd-click toggles an item and
+    /// shift-click selects a range. Dragging a selected item drags the whole selection, which is
+    /// reported with [`DragDropResponse::CurrentMultiDrag`] and
+    /// [`DragDropResponse::CompletedMultiDrag`]. Selections can't be dragged between lists.
+    pub multi_select: bool,
+    /// Makes the whole item draggable instead of only the part drawn with [`DragHandle::ui`], so
+    /// `item_ui` doesn't have to use the handle. Widgets inside the item keep their clicks, since
+    /// the item is only dragged once the pointer moved a few points while pressed, and widgets
+    /// that are dragged themselves, like sliders, keep their drags. The text of labels in the item
+    /// can't be selected.
+    pub drag_whole_item: bool,
+    /// Time in seconds a drag handle has to be held down before its item is dragged, e.g. 0.3 for
+    /// touch screens, where dragging right away conflicts with scrolling. Moving the pointer
+    /// further than `drag_threshold` before that doesn't drag the item, so that an enclosing
+    /// [`egui::ScrollArea`] can be scrolled. Once the time has passed, the item is armed: it is
+    /// outlined and dragged as soon as the pointer moves. Set to 0 to drag items right away.
+    pub drag_delay: f32,
+    /// Distance in points the pointer has to move while pressed on a drag handle before the item is
+    /// dragged, or with `drag_delay` the distance it may move while the handle is held.
+    pub drag_threshold: f32,
+    /// Accepts files dragged in from outside the app, e.g. from the file manager. While files hover
+    /// over the list, room is made for them at the hovered index, which is reported with
+    /// [`DragDropResponse::CurrentFileDrop`] and [`DragDropResponse::CompletedFileDrop`]. Only used
+    /// by [`DragDropUi::list_ui`].
+    pub accept_files: bool,
+    /// Removes items that are released outside of the list, like icons dragged off a dock. The
+    /// item fades while it would be removed, and once released the list returns
+    /// [`DragDropResponse::Removed`]. Items dropped into other lists or on a [`DropZone`] aren't
+    /// removed, and neither are selections dragged with [`DragDropUi::multi_select`].
+    pub remove_on_drop_outside: bool,
+    /// Modifier keys that copy the dragged item instead of moving it, e.g.
+    /// `Modifiers::ALT | Modifiers::COMMAND` to copy while either alt or ctrl/cmd is held. While
+    /// one of them is held the cursor and a "+" badge on the dragged item show that it will be
+    /// copied, and with [`DropIndicator::Reflow`] the item stays in place while an empty slot
+    /// shows where the copy will be inserted. Dropping it returns
+    /// [`DragDropResponse::CompletedCopy`]. Only single items
+    /// dragged with the pointer are copied, and not when swapping. Items dropped into other lists
+    /// are copied as well, which the other list reports with
+    /// [`DragDropResponse::CompletedTransferCopy`]. Defaults to [`Modifiers::NONE`], which never
+    /// copies.
+    pub copy_modifiers: Modifiers,
+    /// Makes the list a source of items for other lists, e.g. a palette of templates. Its items
+    /// can't be reordered, dropping them into another list always copies them (see
+    /// [`DragDropResponse::CompletedTransferCopy`]), and nothing can be dropped into it. Needs an
+    /// [`DragDropUi::id`] to drag items into other lists, which virtual lists can't.
+    pub source_only: bool,
+    /// Distance from the edges of an enclosing [`egui::ScrollArea`] within which dragging an item
+    /// scrolls the area. Set to 0 to disable auto scrolling.
+    pub auto_scroll_margin: f32,
+    /// Scroll speed in points per second when the pointer is right at the edge of the scroll area.
+    /// The speed decreases linearly towards the inner end of `auto_scroll_margin`.
+    pub auto_scroll_speed: f32,
+    /// Time in seconds it takes items to move to their new position while dragging, and for the
+    /// dragged item to move into its slot when dropped. Set to 0 to disable animations, e.g. for
+    /// users that prefer reduced motion.
+    pub animation_time: f32,
+    /// Looks of the item while it's dragged, e.g. [`DragStyle::lifted`] to lift it off the list
+    pub drag_style: DragStyle,
+    /// Whether the items move to make room for the dragged item, or stay in place while a line
+    /// shows where it will be inserted. [`DragDropUi::tree_ui`] always uses lines.
+    pub drop_indicator: DropIndicator,
+    /// Whether dropped items are inserted between the other items or swapped with the item they are
+    /// dropped on, e.g. for equipment slots. Selections, items from other lists, payloads and files
+    /// are always inserted. Not used by [`DragDropUi::tree_ui`].
+    pub drop_behavior: DropBehavior,
+}
+
+/// An item whose drag handle is held down, see [`DragDropUi::drag_delay`]
+#[derive(Clone, Copy)]
+struct HeldItem {
+    id: Id,
+    /// Pointer position when the item was armed, once `drag_delay` has passed
+    armed_at: Option<Pos2>,
+}
+
+/// Something other than a list item that can be dropped in a list
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ForeignDrop {
+    /// An egui drag and drop payload, see [`DragDropUi::accept_payload`]
+    Payload,
+    /// Files dragged in from outside the app, see [`DragDropUi::accept_files`]
+    Files,
+}
+
+type CanDrag = dyn Fn(usize) -> bool + Send + Sync;
+type CanDrop = dyn Fn(usize, usize) -> bool + Send + Sync;
+type PlaceholderUi = dyn Fn(&mut Ui, PlaceholderItem, Rect) + Send + Sync;
+
+/// An item dragged out of a list, shared with the other lists via egui memory so they can accept it.
+#[derive(Clone, Copy)]
+struct Transfer {
+    source: ListIndex,
+    item_id: Id,
+    item_size: Vec2,
+    drag_delta: Vec2,
+    /// The list and index the item would be dropped at if released now
+    target: Option<ListIndex>,
+    /// Whether a copy of the item is dropped, see [`DragDropResponse::CompletedTransferCopy`]
+    copy: bool,
+}
+
+impl Transfer {
+    /// Id the current [`Transfer`] is stored under in egui memory
+    fn memory_id() -> Id {
+        Id::new("egui_dnd_transfer")
+    }
+
+    fn load(context: &Context) -> Option<Self> {
+        context.data(|d| d.get_temp(Self::memory_id()))
+    }
+
+    fn store(self, context: &Context) {
+        context.data_mut(|d| d.insert_temp(Self::memory_id(), self));
+    }
+
+    fn clear(context: &Context) {
+        context.data_mut(|d| d.remove::<Self>(Self::memory_id()));
+    }
 }
 
 impl DragDropUi {
+    /// Creates a list that can exchange items with other lists, see [`DragDropUi::id`].
+    pub fn with_id(id: Id) -> Self {
+        Self {
+            id: Some(id),
+            ..Default::default()
+        }
+    }
+
+    /// Ids of the selected items, see [`DragDropUi::multi_select`].
+    pub fn selection(&self) -> &[Id] {
+        &self.selection
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.selection.clear();
+        self.selection_anchor = None;
+    }
+
+    /// Only allows dragging the items at the indices `can_drag` returns true for, e.g. to pin
+    /// headers in place. The drag handles of the other items are drawn without being draggable.
+    pub fn set_can_drag(&mut self, can_drag: impl Fn(usize) -> bool + Send + Sync + 'static) {
+        self.can_drag = Some(Arc::new(can_drag));
+    }
+
+    /// Only allows dropping the item at `source` at the targets `can_drop(source, target)` returns
+    /// true for. Disallowed targets are skipped: the item stays at its source, the placeholder and
+    /// cursor show that it can't be dropped there, and releasing it cancels the drag. `target` has
+    /// the same meaning as [`DragIndices::target`]. For items, payloads and files dragged in from
+    /// elsewhere `source` is the length of the list, and when several items are dragged each of
+    /// them has to be allowed. Not used by [`DragDropUi::tree_ui`].
+    pub fn set_can_drop(
+        &mut self,
+        can_drop: impl Fn(usize, usize) -> bool + Send + Sync + 'static,
+    ) {
+        self.can_drop = Some(Arc::new(can_drop));
+    }
+
+    /// Draws the slot the dragged item would be dropped in with `placeholder_ui(ui, item, rect)`
+    /// instead of the item itself, e.g. to paint an outline. `item` identifies the dragged item of
+    /// this list, or the item, payload or files dragged in from elsewhere. `rect` is the space the
+    /// item takes up, which is also the max rect of `ui`. Takes precedence over
+    /// `draw_drop_preview`. Not used by [`DragDropUi::tree_ui`].
+    pub fn set_placeholder_ui(
+        &mut self,
+        placeholder_ui: impl Fn(&mut Ui, PlaceholderItem, Rect) + Send + Sync + 'static,
+    ) {
+        self.placeholder_ui = Some(Arc::new(placeholder_ui));
+    }
+
+    /// Accepts payloads of type `P` dragged with egui's built-in drag and drop, e.g. from
+    /// [`Ui::dnd_drag_source`] or another list's [`DragHandle::with_payload`]. While such a payload
+    /// hovers over the list, room is made for it at the hovered index, which is reported with
+    /// [`DragDropResponse::CurrentPayloadDrop`] and [`DragDropResponse::CompletedPayloadDrop`].
+    /// Only used by [`DragDropUi::list_ui`].
+    pub fn accept_payload<P: Any + Send + Sync>(&mut self) {
+        self.has_accepted_payload = Some(DragAndDrop::has_payload_of_type::<P>);
+    }
+
     /// Draws the list of `items` to `ui` using `item_ui` for each item in the list. Returns the
     /// dragging response (to be actioned by the caller).
+    ///
+    /// Items can also be reordered with the keyboard: once a drag handle has focus (e.g. with tab),
+    /// space picks the item up, the arrow keys along the list direction move it, enter drops it and
+    /// escape cancels the drag. This is reported with the same responses as dragging with the pointer.
     pub fn list_ui<'a, T: DragableItem + 'a>(
         &mut self,
         context: &Context,
@@ -92,16 +427,75 @@ impl DragDropUi {
         items: impl Iterator<Item = &'a T>,
         mut item_ui: impl FnMut(&mut Ui, DragHandle, usize, &T),
     ) -> DragDropResponse {
-        // internal list representation shifted according to previous hover state
-        let mut list = items.enumerate().collect::<Vec<_>>();
+        // internal list representation shifted according to previous hover state. `None` marks
+        // where an item, payload or files dragged in from elsewhere would be dropped.
+        let mut list = items
+            .enumerate()
+            .map(|(idx, item)| (idx, Some(item)))
+            .collect::<Vec<_>>();
 
-        let list_len = list.len();
-        if list_len == 0 {
+        let items_len = list.len();
+        let accepts_foreign = self.has_accepted_payload.is_some() || self.accept_files;
+        if items_len == 0 && self.id.is_none() && !accepts_foreign {
             return DragDropResponse::NoDrag;
         }
 
-        if let Some(drag_indices) = self.drag_indices {
-            let shift_res = shift_slice(drag_indices.source, drag_indices.target, &mut list);
+        // item from another list being dragged, which could be dropped in this list
+        let released = ui.input(|i| i.pointer.any_released());
+        let transfer = self.foreign_transfer(context, released);
+        if let Some(transfer) = transfer {
+            self.drag_delta = Some(transfer.drag_delta);
+        }
+        // payload of another egui widget or files being dragged, which could be dropped in this list
+        let foreign = transfer.is_none().then(|| self.foreign_drop(ui)).flatten();
+        if self.incoming.is_some() || self.foreign_drop.is_some() {
+            list.push((items_len, None));
+            self.drag_indices.get_or_insert(DragIndices {
+                source: items_len,
+                target: items_len,
+            });
+        } else if transfer.is_some() || foreign.is_some() {
+            self.drag_indices = None;
+        }
+
+        let item_ids = if self.multi_select {
+            list.iter()
+                .filter_map(|(_, item)| item.map(|item| item.drag_id()))
+                .collect::<Vec<_>>()
+        } else {
+            Vec::new()
+        };
+
+        // the items are drawn at their preview positions, unless the target is shown with a line.
+        // the indices are checked either way.
+        let list_len = list.len();
+        let reflow = self.drop_indicator == DropIndicator::Reflow;
+        let mut unshifted;
+        let preview_list = if reflow {
+            &mut list
+        } else {
+            unshifted = list.clone();
+            &mut unshifted
+        };
+        if let Some(multi_drag) = &self.multi_drag {
+            if let Some(target) = multi_drag.target {
+                if shift_slice_many(&multi_drag.sources, target, preview_list).is_err() {
+                    // current drag indices are busted!
+                    self.reset_drag();
+                }
+            }
+        } else if let (Some(drag_indices), Some(_), true) =
+            (self.drag_indices, self.copy_preview, reflow)
+        {
+            // a copy is previewed in an empty slot at its target, the item itself stays in place
+            let target = drag_indices.target.min(list_len);
+            preview_list.insert(target, (drag_indices.source, None));
+        } else if let Some(drag_indices) = self.drag_indices {
+            let shift_res = if self.swapping() {
+                swap_slice(drag_indices.source, drag_indices.target, preview_list)
+            } else {
+                shift_slice(drag_indices.source, drag_indices.target, preview_list)
+            };
 
             if let Err(_e) = shift_res {
                 // current drag indices are busted!
@@ -110,51 +504,629 @@ impl DragDropUi {
                 self.drag_indices = Some(DragIndices { source, target });
             }
         }
+        // keep animating items for a moment after dragging ended, see `animate_item`
+        if self.drag_indices.is_some() {
+            self.last_drag_time = ui.input(|i| i.time);
+        }
+
         let mut item_rects = Vec::with_capacity(list.len());
+        let mut dragged_item_id = None;
+        // item previewed in the copy slot, see `copy_preview`
+        let copied_item_id = self
+            .copy_preview
+            .and(self.drag_indices)
+            .filter(|_| reflow && self.multi_drag.is_none())
+            .and_then(|drag_indices| {
+                list.iter()
+                    .find(|(idx, _)| *idx == drag_indices.source)
+                    .and_then(|(_, item)| item.map(|item| item.drag_id()))
+            });
+        let mut focused_item = None;
 
         // draw list entries
         let this_list_is_drop_target = self.drag_indices.is_some();
         let list_response = Self::draw_list(ui, this_list_is_drop_target, |ui| {
+            if list.is_empty() {
+                // leave some room to drop items into
+                ui.allocate_space(ui.spacing().interact_size);
+            }
             list.iter_mut().for_each(|(idx, item)| {
+                let Some(item) = item else {
+                    if !reflow {
+                        // the line shows where it will be inserted
+                        return;
+                    }
+
+                    // reserve space for the copy of the dragged item
+                    if let (Some(item_size), true) = (self.copy_preview, *idx < items_len) {
+                        let (_id, rect) = ui.allocate_space(item_size);
+                        if let Some(item_id) = copied_item_id {
+                            let item = PlaceholderItem::Item {
+                                index: *idx,
+                                item_id,
+                            };
+                            self.draw_custom_placeholder(ui, item, rect);
+                        }
+                        item_rects.push((*idx, rect));
+                        return;
+                    }
+
+                    // reserve space for the item, payload or files being dragged in
+                    let item_size = self
+                        .incoming
+                        .map_or(ui.spacing().interact_size, |t| t.item_size);
+                    let (_id, rect) = ui.allocate_space(item_size);
+                    self.draw_custom_placeholder(ui, self.dragged_in(), rect);
+                    item_rects.push((*idx, rect));
+                    return;
+                };
+
+                // highlight selected items. this is also the first shape of the item, see
+                // `animate_item`
+                let selection_background = ui.painter().add(Shape::Noop);
+
                 // get rect of list entry
-                let rect = self.draw_item(context, ui, item.drag_id(), |ui, handle| {
-                    item_ui(ui, handle, *idx, item);
-                });
-                item_rects.push((*idx, rect));
+                let in_dragged_selection = self
+                    .multi_drag
+                    .as_ref()
+                    .is_some_and(|multi_drag| multi_drag.sources.contains(idx));
+                let draggable = self.can_drag_item(*idx);
+                let rect = self.draw_item(
+                    ui,
+                    item.drag_id(),
+                    Some(*idx),
+                    in_dragged_selection,
+                    draggable,
+                    |ui, handle| {
+                        item_ui(ui, handle, *idx, item);
+                    },
+                );
+                // the copied item keeps its place, but its slot is where it will be inserted
+                let copied = copied_item_id == Some(item.drag_id());
+                if !copied {
+                    item_rects.push((*idx, rect));
+                }
+
+                let is_selected = self.multi_select && self.selection.contains(&item.drag_id());
+                if is_selected || self.keyboard_drag == Some(item.drag_id()) {
+                    let visuals = &ui.visuals().selection;
+                    let shape = epaint::RectShape::filled(
+                        rect.expand(2.0),
+                        ui.visuals().widgets.active.rounding,
+                        visuals.bg_fill.gamma_multiply(0.5),
+                    );
+                    ui.painter().set(selection_background, shape);
+                }
 
                 // check if this entry is being dragged
                 let is_being_dragged = context.is_being_dragged(item.drag_id());
                 if is_being_dragged {
                     self.set_source_index(*idx);
+                    dragged_item_id = Some(item.drag_id());
+                } else {
+                    self.animate_item(ui, item.drag_id(), selection_background, rect.min);
+                }
+
+                if context.memory(|m| m.has_focus(item.drag_id())) {
+                    focused_item = Some((*idx, item.drag_id()));
                 }
             });
         });
 
+        // move the item picked up with the keyboard
+        let keyboard_response = self.update_keyboard_drag(ui, focused_item, items_len);
+
+        if self.multi_select {
+            if let Some(clicked_item) = self.clicked_item.take() {
+                let modifiers = ui.input(|i| i.modifiers);
+                self.update_selection(clicked_item, &item_ids, modifiers);
+            }
+            if let (Some(item_id), false) = (dragged_item_id, self.source_only) {
+                self.start_multi_drag(item_id, &item_ids);
+            }
+        }
+
+        // share the dragged item with other lists
+        if let (Some(id), Some(item_id), Some(drag_indices), None) = (
+            self.id,
+            dragged_item_id,
+            self.drag_indices,
+            &self.multi_drag,
+        ) {
+            let item_size = item_rects
+                .iter()
+                .find(|(entry_idx, _)| *entry_idx == drag_indices.source)
+                .map_or(Vec2::ZERO, |(_, rect)| rect.size());
+            let target = Transfer::load(context)
+                .filter(|t| t.item_id == item_id)
+                .and_then(|t| t.target);
+            Transfer {
+                source: ListIndex {
+                    list_id: id,
+                    index: drag_indices.source,
+                },
+                item_id,
+                item_size,
+                drag_delta: self.drag_delta.unwrap_or_default(),
+                target,
+                copy: self.copying,
+            }
+            .store(context);
+        }
+
+        // share the dragged item with drop zones
+        if let (Some(item_id), Some(drag_indices), None) =
+            (dragged_item_id, self.drag_indices, &self.multi_drag)
+        {
+            self.share_with_drop_zones(context, drag_indices.source, item_id);
+        }
+
+        // scroll towards the items hidden beyond the edges of a scroll area
+        if self.drag_indices.is_some() && self.keyboard_drag.is_none() && !released {
+            self.auto_scroll(ui, list_response.rect);
+        }
+
         // determine target index
-        let list_hovered_over = list_response.hovered();
-        let hovering_idx = self.determine_hovering_index(ui, list.len(), item_rects);
-        if let Some(drag_indices) = &mut self.drag_indices {
-            if list_hovered_over && hovering_idx.is_some() {
-                // pending [if-let chains](https://github.com/rust-lang/rfcs/blob/master/text/2497-if-let-chains.md#rollout-plan-and-transitioning-to-rust-2018)...
-                drag_indices.target = hovering_idx.expect("checked for some in previous line");
-            } else {
+        let list_hovered_over = ui.rect_contains_pointer(list_response.rect);
+        // items of a source-only list can't be dropped into it
+        let hovering_idx = self
+            .determine_hovering_index(ui, &item_rects, items_len)
+            .filter(|_| !self.source_only);
+        if let (Some(id), Some(mut transfer)) = (self.id, transfer) {
+            let target = match hovering_idx {
+                Some(hovering_idx) if list_hovered_over => {
+                    self.drag_indices = Some(DragIndices {
+                        source: items_len,
+                        target: hovering_idx,
+                    });
+                    self.incoming = Some(transfer);
+                    Some(ListIndex {
+                        list_id: id,
+                        index: hovering_idx.min(items_len),
+                    })
+                }
+                // not hovering over this list -> not a drop target
+                _ => {
+                    self.reset_drag();
+                    None
+                }
+            };
+
+            // let the source list know whether the item is about to be dropped here
+            if target.is_some() || transfer.target.is_some_and(|t| t.list_id == id) {
+                transfer.target = target;
+                transfer.store(context);
+            }
+        } else if let Some(foreign) = foreign {
+            match hovering_idx {
+                Some(hovering_idx) if list_hovered_over => {
+                    self.drag_indices = Some(DragIndices {
+                        source: items_len,
+                        target: hovering_idx,
+                    });
+                    self.foreign_drop = Some(foreign);
+                }
+                // not hovering over this list -> not a drop target
+                _ => self.reset_drag(),
+            }
+        } else if let (Some(drag_indices), None) = (&mut self.drag_indices, self.keyboard_drag) {
+            match hovering_idx {
+                Some(hovering_idx) if list_hovered_over => drag_indices.target = hovering_idx,
                 // no index being hovered over -> no target
-                drag_indices.target = drag_indices.source;
+                _ => drag_indices.target = drag_indices.source,
+            }
+            if let Some(multi_drag) = &mut self.multi_drag {
+                multi_drag.target = hovering_idx.filter(|_| list_hovered_over);
             }
         }
 
-        // return dragging state
-        if let Some(drag_indices) = self.drag_indices.clone() {
+        // show that the dragged items can't be dropped where they are hovering
+        let drop_not_allowed = list_hovered_over && hovering_idx.is_none();
+        if self.drag_indices.is_some() && self.keyboard_drag.is_none() {
+            self.set_drop_not_allowed(ui, drop_not_allowed);
+        } else if (transfer.is_some() || foreign.is_some()) && drop_not_allowed {
+            context.set_cursor_icon(CursorIcon::NotAllowed);
+        }
+
+        // fade the dragged item while releasing it would remove it
+        if let (Some(drag_indices), Some(_)) = (self.drag_indices, dragged_item_id) {
+            let transferring = self.id.is_some()
+                && Transfer::load(context)
+                    .and_then(|t| t.target)
+                    .is_some_and(|t| Some(t.list_id) != self.id);
+            let outside = !list_hovered_over && !transferring;
+            self.update_drop_removes(context, drag_indices.source, outside);
+            let hovering_target = list_hovered_over && hovering_idx.is_some();
+            self.update_copying(ui, hovering_target || transferring);
+
+            // preview the copy at its target in this list
+            let item_size = item_rects
+                .iter()
+                .find(|(entry_idx, _)| *entry_idx == drag_indices.source)
+                .map(|(_, rect)| rect.size());
+            self.copy_preview =
+                item_size.filter(|_| self.copying && reflow && hovering_target && !released);
+        }
+
+        // show where the dragged items will be inserted, or the item they will be swapped with
+        if let (DropIndicator::Line { .. }, Some(drag_indices)) =
+            (self.drop_indicator, self.drag_indices)
+        {
+            let target = match &self.multi_drag {
+                Some(multi_drag) => multi_drag.target,
+                None => Some(drag_indices.target),
+            };
+            let hovering = list_hovered_over && hovering_idx.is_some();
+            if let (Some(target), true) = (target, hovering || self.keyboard_drag.is_some()) {
+                if !self.swapping() {
+                    paint_insertion_line(ui, &item_rects, list_response.rect, target);
+                } else if target != drag_indices.source {
+                    if let Some((_, rect)) = item_rects.iter().find(|(idx, _)| *idx == target) {
+                        paint_swap_outline(ui, *rect);
+                    }
+                }
+            }
+        }
+
+        // dropping the item here would leave it where it is
+        let outside_list = !list_hovered_over || hovering_idx.is_none();
+        let escape_pressed = ui.input(|i| i.key_pressed(Key::Escape));
+
+        // return transfer state
+        if let (Some(id), Some(incoming), Some(drag_indices)) =
+            (self.id, self.incoming, self.drag_indices)
+        {
+            let transfer_indices = TransferIndices {
+                source: incoming.source,
+                target: ListIndex {
+                    list_id: id,
+                    index: drag_indices.target.min(items_len),
+                },
+            };
+
+            // dropped into this list
+            if released {
+                self.reset_drag();
+                if incoming.copy {
+                    return DragDropResponse::CompletedTransferCopy(transfer_indices);
+                }
+                return DragDropResponse::CompletedTransfer(transfer_indices);
+            }
+
+            // dragging over this list
+            return DragDropResponse::CurrentTransfer(transfer_indices);
+        }
+
+        // return payload and file drop state
+        if let (Some(foreign), Some(drag_indices)) = (self.foreign_drop, self.drag_indices) {
+            let index = drag_indices.target.min(items_len);
+            match foreign {
+                ForeignDrop::Payload => {
+                    // dropped into this list
+                    if released {
+                        self.reset_drag();
+                        return DragDropResponse::CompletedPayloadDrop(index);
+                    }
+
+                    // dragging over this list
+                    return DragDropResponse::CurrentPayloadDrop(index);
+                }
+                ForeignDrop::Files => {
+                    // dropped into this list
+                    let dropped_files = ui.input(|i| i.raw.dropped_files.clone());
+                    if !dropped_files.is_empty() {
+                        self.reset_drag();
+                        return DragDropResponse::CompletedFileDrop(index, dropped_files);
+                    }
+
+                    // hovering over this list
+                    return DragDropResponse::CurrentFileDrop(index);
+                }
+            }
+        }
+
+        // return multi dragging state
+        if let Some(multi_drag) = self.multi_drag.clone() {
+            // dragging cancelled
+            if escape_pressed || (released && multi_drag.target.is_none()) {
+                let drag_indices = self.drag_indices.unwrap_or_default();
+                self.reset_drag();
+                return DragDropResponse::Cancelled(drag_indices);
+            }
+
             // dragging finished
-            if ui.input(|i| i.pointer.any_released()) {
-                self.drag_indices = None;
-                return DragDropResponse::Completed(drag_indices);
+            if released {
+                self.reset_drag();
+                return DragDropResponse::CompletedMultiDrag(multi_drag);
             }
 
             // dragging in progress
-            return DragDropResponse::CurrentDrag(drag_indices);
+            return DragDropResponse::CurrentMultiDrag(multi_drag);
+        }
+
+        // return dragging state
+        if let Some(keyboard_response) = keyboard_response {
+            return keyboard_response;
+        }
+        if let Some(drag_indices) = self.drag_indices {
+            let finished = self.finish_pointer_drag(
+                context,
+                drag_indices,
+                released,
+                escape_pressed,
+                outside_list,
+            );
+            // dragging in progress
+            return finished.unwrap_or(DragDropResponse::CurrentDrag(drag_indices));
+        }
+        DragDropResponse::NoDrag
+    }
+
+    /// Updates whether releasing the item at `source` would remove it from the list, see
+    /// [`DragDropUi::remove_on_drop_outside`]. `outside` is whether the item is outside of the list
+    /// and any other list it could be dropped into.
+    fn update_drop_removes(&mut self, context: &Context, source: usize, outside: bool) {
+        // source-only lists never lose their items, and selections are never removed
+        self.drop_removes = self.remove_on_drop_outside
+            && !self.source_only
+            && self.multi_drag.is_none()
+            && outside
+            && !self.over_drop_zone(context, source, false);
+    }
+
+    /// Ends the pointer drag of a single item once it's `released` or `escape_pressed`, and returns
+    /// what happened to it. `outside_list` is whether it was released where it can't be dropped,
+    /// which cancels the drag. Returns `None` while the item is still dragged. Items picked up with
+    /// the keyboard are dropped in `update_keyboard_drag` instead.
+    fn finish_pointer_drag(
+        &mut self,
+        context: &Context,
+        drag_indices: DragIndices,
+        released: bool,
+        escape_pressed: bool,
+        outside_list: bool,
+    ) -> Option<DragDropResponse> {
+        if self.keyboard_drag.is_some() || !(released || escape_pressed) {
+            return None;
+        }
+
+        let (remove, copy) = (self.drop_removes, self.copying);
+        self.reset_drag();
+        if self.id.is_some() {
+            let transferred = Transfer::load(context)
+                .and_then(|t| t.target)
+                .is_some_and(|t| Some(t.list_id) != self.id);
+            Transfer::clear(context);
+            if transferred && !escape_pressed {
+                // dropped into another list, which reports the transfer
+                return Some(DragDropResponse::NoDrag);
+            }
+        }
+        if self.over_drop_zone(context, drag_indices.source, true) && !escape_pressed {
+            // dropped on a drop zone, which reports the item
+            return Some(DragDropResponse::NoDrag);
+        }
+        Some(if remove && !escape_pressed {
+            DragDropResponse::Removed(drag_indices.source)
+        } else if escape_pressed || outside_list {
+            DragDropResponse::Cancelled(drag_indices)
+        } else if copy {
+            DragDropResponse::CompletedCopy(drag_indices)
+        } else {
+            DragDropResponse::Completed(drag_indices)
+        })
+    }
+
+    /// Returns the item currently being dragged out of another list, if this list can accept it.
+    fn foreign_transfer(&mut self, context: &Context, released: bool) -> Option<Transfer> {
+        let id = self.id.filter(|_| !self.source_only)?;
+
+        if let Some(incoming) = self.incoming {
+            if released || context.dragged_id() == Some(incoming.item_id) {
+                // the source list may have updated it, e.g. whether it's copied
+                let transfer = Transfer::load(context).filter(|t| t.item_id == incoming.item_id);
+                return Some(transfer.unwrap_or(incoming));
+            }
+            // the drag ended elsewhere
+            self.reset_drag();
+        }
+
+        Transfer::load(context)
+            .filter(|t| t.source.list_id != id && context.dragged_id() == Some(t.item_id))
+    }
+
+    /// Returns what is being dragged by another egui widget or from outside the app, if this list
+    /// accepts it. See [`DragDropUi::accept_payload`] and [`DragDropUi::accept_files`].
+    fn foreign_drop(&mut self, ui: &Ui) -> Option<ForeignDrop> {
+        // this list's own items are being dragged, or nothing can be dropped into it
+        if (self.drag_indices.is_some() && self.foreign_drop.is_none()) || self.source_only {
+            return None;
+        }
+
+        let dragging_payload = self
+            .has_accepted_payload
+            .is_some_and(|has_payload| has_payload(ui.ctx()));
+        // files are still reported on the frame they are dropped
+        let dragging_files = self.accept_files
+            && ui.input(|i| !i.raw.hovered_files.is_empty() || !i.raw.dropped_files.is_empty());
+        let foreign = if dragging_payload {
+            Some(ForeignDrop::Payload)
+        } else if dragging_files {
+            Some(ForeignDrop::Files)
+        } else {
+            None
+        };
+
+        if self.foreign_drop.is_some() && self.foreign_drop != foreign {
+            // dropped or taken elsewhere
+            self.reset_drag();
+        }
+        foreign
+    }
+
+    fn reset_drag(&mut self) {
+        self.drag_indices = None;
+        self.drag_delta = None;
+        self.incoming = None;
+        self.multi_drag = None;
+        self.keyboard_drag = None;
+        self.drop_not_allowed = false;
+        self.drop_removes = false;
+        self.copying = false;
+        self.copy_preview = None;
+        self.foreign_drop = None;
+    }
+
+    /// Picks up the item whose drag handle has focus when space is pressed, then moves its target
+    /// with the arrow keys along the main direction of the `ui` layout. `focused_item` is the index
+    /// and id of the focused item. Returns [`DragDropResponse::Completed`] once the item is dropped
+    /// with enter, or [`DragDropResponse::Cancelled`] if escape is pressed or the handle loses focus,
+    /// e.g. by clicking elsewhere.
+    fn update_keyboard_drag(
+        &mut self,
+        ui: &Ui,
+        focused_item: Option<(usize, Id)>,
+        items_len: usize,
+    ) -> Option<DragDropResponse> {
+        if let Some(grabbed_id) = self.keyboard_drag {
+            if focused_item.map(|(_, id)| id) != Some(grabbed_id) {
+                // focus moved somewhere else -> leave the item where it was
+                let drag_indices = self.drag_indices;
+                self.reset_drag();
+                return drag_indices.map(DragDropResponse::Cancelled);
+            }
+        }
+        let (idx, id) = focused_item?;
+
+        if self.keyboard_drag.is_none() {
+            // a pointer drag is in progress, or the items can't be reordered
+            if self.drag_indices.is_some() || self.source_only {
+                return None;
+            }
+            if !ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Space)) {
+                return None;
+            }
+            self.drag_indices = Some(DragIndices {
+                source: idx,
+                target: idx,
+            });
+            self.keyboard_drag = Some(id);
+        }
+
+        // keep tab, arrow keys and escape from moving the focus away while the item is picked up
+        let event_filter = EventFilter {
+            tab: true,
+            horizontal_arrows: true,
+            vertical_arrows: true,
+            escape: true,
+        };
+        ui.memory_mut(|m| m.set_focus_lock_filter(id, event_filter));
+
+        let (forward, backward) = match ui.layout().main_dir() {
+            Direction::LeftToRight => (Key::ArrowRight, Key::ArrowLeft),
+            Direction::RightToLeft => (Key::ArrowLeft, Key::ArrowRight),
+            Direction::TopDown => (Key::ArrowDown, Key::ArrowUp),
+            Direction::BottomUp => (Key::ArrowUp, Key::ArrowDown),
+        };
+        let mut drag_indices = self.drag_indices?;
+        let source = drag_indices.source;
+
+        let (forward_pressed, backward_pressed, dropped, cancelled) = ui.input_mut(|i| {
+            (
+                i.consume_key(Modifiers::NONE, forward),
+                i.consume_key(Modifiers::NONE, backward),
+                i.consume_key(Modifiers::NONE, Key::Enter),
+                i.consume_key(Modifiers::NONE, Key::Escape),
+            )
+        });
+
+        // position of the item in the reordered list. when swapping it is the target itself.
+        let swap = self.swapping();
+        let mut position = if !swap && drag_indices.target > source {
+            drag_indices.target - 1
+        } else {
+            drag_indices.target
+        };
+        let target_at = |position: usize| {
+            if !swap && position > source {
+                position + 1
+            } else {
+                position
+            }
+        };
+        // move to the next position the item can be dropped at
+        let allowed = |position: &usize| self.can_drop_at(target_at(*position));
+        if forward_pressed {
+            position = (position + 1..items_len).find(allowed).unwrap_or(position);
+        }
+        if backward_pressed {
+            position = (0..position).rev().find(allowed).unwrap_or(position);
+        }
+        drag_indices.target = target_at(position);
+        self.drag_indices = Some(drag_indices);
+
+        if dropped || cancelled {
+            self.reset_drag();
+            ui.memory_mut(|m| m.set_focus_lock_filter(id, EventFilter::default()));
+            return Some(if cancelled {
+                DragDropResponse::Cancelled(drag_indices)
+            } else {
+                DragDropResponse::Completed(drag_indices)
+            });
+        }
+        None
+    }
+
+    /// Updates the selection after the drag handle of `clicked_item` was clicked. `item_ids` are the
+    /// ids of all items in the list.
+    fn update_selection(&mut self, clicked_item: Id, item_ids: &[Id], modifiers: Modifiers) {
+        let position = |id| item_ids.iter().position(|item_id| *item_id == id);
+
+        if modifiers.shift {
+            // select the range between the anchor and the clicked item
+            let anchor_idx = self.selection_anchor.and_then(position);
+            if let (Some(anchor_idx), Some(clicked_idx)) = (anchor_idx, position(clicked_item)) {
+                let range = anchor_idx.min(clicked_idx)..=anchor_idx.max(clicked_idx);
+                self.selection = item_ids[range].to_vec();
+                return;
+            }
+        }
+
+        if modifiers.command {
+            // toggle the clicked item
+            if let Some(selected_idx) = self.selection.iter().position(|id| *id == clicked_item) {
+                self.selection.remove(selected_idx);
+            } else {
+                self.selection.push(clicked_item);
+            }
+        } else {
+            self.selection = vec![clicked_item];
+        }
+        self.selection_anchor = Some(clicked_item);
+    }
+
+    /// Drags the whole selection along with `dragged_item` if it is selected, otherwise selects
+    /// only `dragged_item`.
+    fn start_multi_drag(&mut self, dragged_item: Id, item_ids: &[Id]) {
+        if self.multi_drag.is_some() {
+            return;
+        }
+        if !self.selection.contains(&dragged_item) {
+            self.selection = vec![dragged_item];
+            self.selection_anchor = Some(dragged_item);
+        }
+        if self.selection.len() > 1 {
+            let sources = item_ids
+                .iter()
+                .enumerate()
+                .filter(|(idx, id)| self.selection.contains(id) && self.can_drag_item(*idx))
+                .map(|(idx, _)| idx)
+                .collect();
+            self.multi_drag = Some(MultiDragIndices {
+                sources,
+                target: None,
+            });
         }
-        return DragDropResponse::NoDrag;
     }
 
     /// Draw the list body and _todo: what other stuff?_
@@ -168,16 +1140,17 @@ impl DragDropUi {
         let outer_rect_bounds = ui.available_rect_before_wrap(); // big ol box
         let inner_rect = outer_rect_bounds.shrink2(margin); // minus margin
         let where_to_put_background = ui.painter().add(Shape::Noop); // assign background shape before drawing list body
-        let mut content_ui = ui.child_ui(inner_rect, *ui.layout(), None); // we'll draw list body to child ui thats within margin
-                                                                          //let mut content_ui = ui.new_child(ui_builder);
+        let mut content_ui = ui.new_child(UiBuilder::new().max_rect(inner_rect)); // we'll draw list body to child ui thats within margin
 
         list_body(&mut content_ui);
         let mut outer_rect = content_ui.min_rect().expand2(margin);
-        outer_rect.max.x = content_ui.max_rect().max.x + margin.x; // expand outer box horizontally for padding
+        if ui.layout().main_dir().is_vertical() {
+            outer_rect.max.x = content_ui.max_rect().max.x + margin.x; // expand outer box horizontally for padding
+        }
         let (rect, response) = ui.allocate_at_least(outer_rect.size(), Sense::hover());
 
         // determine list coloring depending on wherever this list is currently the drop target
-        let style = if is_drop_target && response.hovered() {
+        let style = if is_drop_target && ui.rect_contains_pointer(rect) {
             ui.visuals().widgets.active
         } else {
             ui.visuals().widgets.inactive
@@ -201,34 +1174,67 @@ impl DragDropUi {
     }
 
     /// Draw the widget for an item using `item_body` either inline with the list or hovering depending
-    /// on if its being dragged, then returns its rect. If the item is being dragged, a preview indicator
-    /// is drawn in the target list position using the function `drop_place_preview`. If none is provided,
-    /// a blank area is reserved in place.
+    /// on if its being dragged, then returns its rect. If the item is being dragged, or `placeholder`
+    /// is set because it's part of a dragged selection, a preview of the item is drawn in the target
+    /// list position if `draw_drop_preview` is set. Otherwise a blank area is reserved in place.
+    /// `index` is the index of the item in the list, used for the custom placeholder. The drag
+    /// handle of the item only drags it if `draggable` is set.
     fn draw_item(
         &mut self,
-        context: &Context,
         ui: &mut Ui,
         id: Id,
+        index: Option<usize>,
+        placeholder: bool,
+        draggable: bool,
         mut item_body: impl FnMut(&mut Ui, DragHandle),
     ) -> Rect {
-        let is_being_dragged = context.is_being_dragged(id);
+        let is_being_dragged = ui.ctx().is_being_dragged(id);
 
         if !is_being_dragged {
+            if placeholder {
+                return self.draw_placeholder(ui, id, index, item_body);
+            }
+
             // not dragged -> draw widget to ui
-            let scope = ui.scope(|ui| {
-                item_body(
-                    ui,
-                    DragHandle {
-                        state: self,
-                        placeholder: false,
-                    },
-                )
+            let whole_item = self.drag_whole_item && draggable;
+            let delays_drag = self.delays_drag();
+            let scope = item_scope(ui, id, |ui| {
+                let handle = DragHandle {
+                    state: self,
+                    placeholder: false,
+                    draggable,
+                    payload: None,
+                };
+                if !whole_item {
+                    item_body(ui, handle);
+                    return None;
+                }
+
+                // the background of the item is dragged. it is below the widgets of the item, so
+                // that they keep their clicks and drags. labels are dragged along instead of
+                // selecting their text.
+                let sense = if delays_drag {
+                    Sense::click()
+                } else {
+                    Sense::click_and_drag()
+                };
+                let sense = Sense {
+                    focusable: false,
+                    ..sense
+                };
+                let scope = ui.scope_builder(UiBuilder::new().sense(sense), |ui| {
+                    ui.style_mut().interaction.selectable_labels = false;
+                    item_body(ui, handle)
+                });
+                Some(scope.response)
             });
+            if let Some(background) = scope.inner {
+                self.drag_whole_item_by(ui, id, &background);
+            }
+            self.paint_armed_outline(ui, id, scope.response.rect);
             return scope.response.rect;
         }
 
-        ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
-
         // draw the body to a new layer
         let _layer_id = LayerId::new(Order::Tooltip, id);
 
@@ -239,111 +1245,502 @@ impl DragDropUi {
         // (anything with `Order::Tooltip` always gets an empty [`Response`])
         // So this is fine!
 
+        // draw the placeholder first so that the drag delta is known before the hovering item is
+        // positioned
+        let placeholder_rect = self.draw_placeholder(ui, id, index, &mut item_body);
+        self.init_drag_delta(ui, placeholder_rect);
+        self.draw_floating_item(ui, id, item_body);
+
+        placeholder_rect
+    }
+
+    /// Drags the item `id` along with the `background` of the item, see
+    /// [`DragDropUi::drag_whole_item`].
+    fn drag_whole_item_by(&mut self, ui: &Ui, id: Id, background: &Response) {
+        // the item itself only takes the keyboard focus, so that it can be picked up with the
+        // keyboard like items with a drag handle
+        let response = ui.interact(background.rect, id, Sense::focusable_noninteractive());
+        if self.delays_drag() {
+            self.start_delayed_drag(ui, id, background);
+        } else if background.drag_started() {
+            ui.ctx().set_dragged_id(id);
+        }
+        handle::show_drag_area(self, ui, id, background, response.has_focus());
+    }
+
+    /// Outlines the item `id` in `rect` if it's armed, i.e. dragged as soon as the pointer moves.
+    /// See [`DragDropUi::drag_delay`].
+    fn paint_armed_outline(&self, ui: &Ui, id: Id, rect: Rect) {
+        if self
+            .held_item
+            .is_some_and(|held_item| held_item.id == id && held_item.armed_at.is_some())
+        {
+            let stroke = Stroke::new(2.0, ui.visuals().selection.stroke.color);
+            let rounding = ui.visuals().widgets.active.rounding;
+            ui.painter().rect_stroke(rect.expand(2.0), rounding, stroke);
+        }
+    }
+
+    /// Whether items are only dragged after holding down or moving the pointer, see
+    /// [`DragDropUi::drag_delay`] and [`DragDropUi::drag_threshold`]
+    fn delays_drag(&self) -> bool {
+        self.drag_delay > 0.0 || self.drag_threshold > 0.0
+    }
+
+    /// Starts dragging the item `id` once its drag handle with `response` has been held down for
+    /// `drag_delay` and the pointer moved, or without a delay once the pointer moved further than
+    /// `drag_threshold`.
+    fn start_delayed_drag(&mut self, ui: &Ui, id: Id, response: &Response) {
+        let pressed = response.is_pointer_button_down_on();
+        if pressed && self.drag_indices.is_none() && self.held_item.is_none_or(|h| h.id != id) {
+            self.held_item = Some(HeldItem { id, armed_at: None });
+        }
+        let Some(held_item) = self.held_item.filter(|held_item| held_item.id == id) else {
+            return;
+        };
+
+        let (down, origin, start_time, pointer_pos, time) = ui.input(|i| {
+            let pointer = &i.pointer;
+            let down = pointer.primary_down();
+            (
+                down,
+                pointer.press_origin(),
+                pointer.press_start_time(),
+                pointer.interact_pos(),
+                i.time,
+            )
+        });
+        let (true, Some(origin), Some(start_time), Some(pointer_pos)) =
+            (down, origin, start_time, pointer_pos)
+        else {
+            // released before the item was dragged, e.g. a click
+            self.held_item = None;
+            return;
+        };
+
+        let start_drag = match held_item.armed_at {
+            // armed -> dragged as soon as the pointer moves
+            Some(armed_at) => pointer_pos != armed_at,
+            None => {
+                let moved = pointer_pos.distance(origin) > self.drag_threshold;
+                let remaining = self.drag_delay - (time - start_time) as f32;
+                if self.drag_delay <= 0.0 {
+                    moved
+                } else if moved {
+                    // moved before the delay passed, e.g. to scroll
+                    self.held_item = None;
+                    false
+                } else if remaining <= 0.0 {
+                    self.held_item = Some(HeldItem {
+                        id,
+                        armed_at: Some(pointer_pos),
+                    });
+                    false
+                } else {
+                    ui.ctx().request_repaint_after_secs(remaining);
+                    false
+                }
+            }
+        };
+        if start_drag {
+            self.held_item = None;
+            ui.ctx().set_dragged_id(id);
+        }
+    }
+
+    /// Draws the dragged item hovering under the pointer. `ui` is the list the item is dragged in.
+    fn draw_floating_item(
+        &mut self,
+        ui: &Ui,
+        id: Id,
+        mut item_body: impl FnMut(&mut Ui, DragHandle),
+    ) {
+        ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+
         // latest pointer position while dragging
         let pointer_pos = ui
             .ctx()
             .pointer_interact_pos()
             .unwrap_or(ui.next_widget_position());
 
-        // draw hovering item at pointer position
-        let hovering_item = egui::Area::new("draggable_item".into())
+        let floating_pos = pointer_pos + self.drag_delta.unwrap_or_default();
+        // the item animates from here into its slot once it is dropped
+        self.animation_offset(ui, id, floating_pos, false);
+
+        // number of items dragged along with this one
+        let dragged_count = self
+            .multi_drag
+            .as_ref()
+            .map_or(1, |multi_drag| multi_drag.sources.len());
+
+        // draw hovering item off screen and move its shapes to the pointer position, like
+        // `Ui::dnd_drag_source` does. this keeps its widgets from covering the widgets under the
+        // pointer, e.g. drop zones of egui's drag and drop.
+        let style = self.drag_style.clone();
+        let frame = style.frame.unwrap_or_default().shadow(style.shadow);
+        let area = egui::Area::new("draggable_item".into())
             .interactable(false)
-            .fixed_pos(pointer_pos + self.drag_delta.unwrap_or(Vec2::default()))
+            .constrain(false)
+            .fixed_pos(Pos2::new(-10_000.0, -10_000.0))
             .show(ui.ctx(), |ui_1| {
-                let item_rect = ui_1
-                    .scope(|ui_2| {
-                        item_body(
-                            ui_2,
-                            DragHandle {
-                                state: self,
-                                placeholder: false,
-                            },
-                        )
+                // the shapes are clipped to the screen once they are moved
+                ui_1.set_clip_rect(Rect::EVERYTHING);
+                // the item fades while releasing it would remove it
+                let removed_opacity = if self.drop_removes { 0.5 } else { 1.0 };
+                ui_1.multiply_opacity(style.opacity * removed_opacity);
+                let rect = frame
+                    .show(ui_1, |ui_2| {
+                        ui_2.scope(|ui_3| {
+                            item_body(
+                                ui_3,
+                                DragHandle {
+                                    state: self,
+                                    placeholder: false,
+                                    draggable: true,
+                                    payload: None,
+                                },
+                            )
+                        })
+                        .response
+                        .rect
                     })
-                    .response
-                    .rect;
-
-                return item_rect;
+                    .inner;
+                if dragged_count > 1 {
+                    paint_badge(ui_1, rect, dragged_count.to_string());
+                } else if self.copying {
+                    paint_badge(ui_1, rect, "+".to_owned());
+                }
+                rect
             });
 
-        if self.draw_drop_preview {
-            let scope = ui.scope(|ui| {
-                // disabled style for placeholder ui
-                ui.add_enabled_ui(false, |ui| {
-                    item_body(
-                        ui,
-                        DragHandle {
-                            state: self,
-                            placeholder: true,
-                        },
-                    )
-                });
-            });
-            return scope.response.rect;
+        // the item itself is at the pointer position, the frame around it
+        let rect = area.inner;
+        let center = rect.center() + (floating_pos - rect.min);
+        let transform = TSTransform::from_translation(center.to_vec2())
+            * TSTransform::from_scaling(style.scale)
+            * TSTransform::from_translation(-rect.center().to_vec2());
+        transform_floating_item(
+            ui.ctx(),
+            area.response.layer_id,
+            transform,
+            style.tilt,
+            center,
+        );
+    }
+
+    /// Draws the item in place of a dragged item: disabled if `draw_drop_preview` is set, otherwise
+    /// invisible so that only its space is reserved. With [`DropIndicator::Line`] it's drawn as it
+    /// is unless it should be dimmed. Items with an `index` are drawn with the custom placeholder if
+    /// there is one, see [`DragDropUi::set_placeholder_ui`]. Returns its rect.
+    fn draw_placeholder(
+        &mut self,
+        ui: &mut Ui,
+        id: Id,
+        index: Option<usize>,
+        mut item_body: impl FnMut(&mut Ui, DragHandle),
+    ) -> Rect {
+        // a copied item stays as it is, its copy gets the custom placeholder
+        let custom_placeholder =
+            index.filter(|_| self.placeholder_ui.is_some() && self.copy_preview.is_none());
+        let rect = item_scope(ui, id, |ui| {
+            self.style_placeholder(ui, custom_placeholder.is_some());
+            item_body(
+                ui,
+                DragHandle {
+                    state: self,
+                    placeholder: true,
+                    draggable: false,
+                    payload: None,
+                },
+            )
+        })
+        .response
+        .rect;
+        if let Some(index) = custom_placeholder {
+            let item = PlaceholderItem::Item { index, item_id: id };
+            self.draw_custom_placeholder(ui, item, rect);
+        }
+        self.paint_drop_not_allowed(ui, rect);
+        rect
+    }
+
+    /// Styles `ui` for drawing the placeholder of the dragged item, see
+    /// [`DragDropUi::draw_placeholder`]. `custom_placeholder` hides it, so that the custom
+    /// placeholder can be drawn in its place.
+    fn style_placeholder(&self, ui: &mut Ui, custom_placeholder: bool) {
+        match self.drop_indicator {
+            _ if custom_placeholder => ui.set_invisible(),
+            // a copied item stays in its slot, the copy is previewed at the target
+            _ if self.copy_preview.is_some() => {}
+            // the item stays in its slot while a line shows the target
+            DropIndicator::Line { dim_dragged } => {
+                if dim_dragged {
+                    ui.disable();
+                }
+            }
+            // disabled style for placeholder ui
+            DropIndicator::Reflow if self.draw_drop_preview => ui.disable(),
+            DropIndicator::Reflow => ui.set_invisible(),
+        }
+    }
+
+    /// Highlights the placeholder in `rect` if the dragged item can't be dropped where it's
+    /// hovering, since it will stay here
+    fn paint_drop_not_allowed(&self, ui: &Ui, rect: Rect) {
+        if self.drop_not_allowed {
+            let color = ui.visuals().error_fg_color;
+            let rounding = ui.visuals().widgets.active.rounding;
+            ui.painter().rect(
+                rect,
+                rounding,
+                color.gamma_multiply(0.2),
+                Stroke::new(1.0, color),
+            );
+        }
+    }
+
+    /// Draws the custom placeholder of `item` in `rect`, see [`DragDropUi::set_placeholder_ui`]
+    fn draw_custom_placeholder(&self, ui: &mut Ui, item: PlaceholderItem, rect: Rect) {
+        if let Some(placeholder_ui) = &self.placeholder_ui {
+            let mut child_ui = ui.new_child(UiBuilder::new().max_rect(rect));
+            placeholder_ui(&mut child_ui, item, rect);
+        }
+    }
+
+    /// The item, payload or files currently dragged into this list from elsewhere
+    fn dragged_in(&self) -> PlaceholderItem {
+        match (self.incoming, self.foreign_drop) {
+            (Some(transfer), _) => PlaceholderItem::Transfer {
+                source: transfer.source,
+                item_id: transfer.item_id,
+            },
+            (None, Some(ForeignDrop::Files)) => PlaceholderItem::Files,
+            (None, _) => PlaceholderItem::Payload,
+        }
+    }
+
+    /// Scrolls the enclosing [`egui::ScrollArea`] while the pointer is within `auto_scroll_margin` of
+    /// one of its edges and `content_rect` continues beyond that edge. The closer the pointer is to
+    /// the edge, the faster it scrolls. The hovered index is determined from the item rects of the
+    /// current frame, so it stays correct while the content moves.
+    fn auto_scroll(&self, ui: &Ui, content_rect: Rect) {
+        let Some(pointer_pos) = ui.input(|i| i.pointer.interact_pos()) else {
+            return;
+        };
+        let visible_rect = ui.clip_rect();
+        if self.auto_scroll_margin <= 0.0 || !visible_rect.contains(pointer_pos) {
+            return;
+        }
+
+        // 0 at the inner end of the margin, 1 right at the edge
+        let proximity = |distance: f32| (1.0 - distance / self.auto_scroll_margin).clamp(0.0, 1.0);
+        let mut velocity = Vec2::ZERO;
+        for axis in 0..2 {
+            if content_rect.min[axis] < visible_rect.min[axis] {
+                velocity[axis] += proximity(pointer_pos[axis] - visible_rect.min[axis]);
+            }
+            if content_rect.max[axis] > visible_rect.max[axis] {
+                velocity[axis] -= proximity(visible_rect.max[axis] - pointer_pos[axis]);
+            }
+        }
+        if velocity == Vec2::ZERO {
+            return;
+        }
+
+        let dt = ui.input(|i| i.stable_dt).min(0.1);
+        ui.scroll_with_delta_animation(
+            velocity * self.auto_scroll_speed * dt,
+            ScrollAnimation::none(),
+        );
+        // keep scrolling while the pointer rests near the edge
+        ui.ctx().request_repaint();
+    }
+
+    /// Paints the item whose shapes start at `first_shape` at its animated position, so that it
+    /// moves smoothly to `pos` instead of jumping there. Items only animate while dragging and for
+    /// `animation_time` after, so that other changes to the list aren't animated.
+    fn animate_item(&self, ui: &Ui, id: Id, first_shape: ShapeIdx, pos: Pos2) {
+        let since_drag = ui.input(|i| i.time) - self.last_drag_time;
+        let offset =
+            self.animation_offset(ui, id, pos, since_drag <= f64::from(self.animation_time));
+        if offset == Vec2::ZERO {
+            return;
+        }
+
+        let last_shape = ui.painter().add(Shape::Noop);
+        let layer_id = ui.layer_id();
+        ui.ctx().graphics_mut(|g| {
+            let shapes = g.entry(layer_id);
+            for idx in first_shape.0..last_shape.0 {
+                shapes.mutate_shape(ShapeIdx(idx), |clipped| clipped.shape.translate(offset));
+            }
+        });
+    }
+
+    /// Returns the offset from `pos` to the animated position of the item. Positions are tracked
+    /// relative to the list, so that scrolling it doesn't animate its items. If `animate` isn't
+    /// set, the item jumps to `pos`.
+    fn animation_offset(&self, ui: &Ui, id: Id, pos: Pos2, animate: bool) -> Vec2 {
+        if self.animation_time <= 0.0 {
+            return Vec2::ZERO;
+        }
+
+        let animation_time = if animate { self.animation_time } else { 0.0 };
+        let animation_id = id.with(ui.id());
+        let target = pos - ui.max_rect().min;
+        let animated = Vec2::new(
+            ui.ctx()
+                .animate_value_with_time(animation_id.with("x"), target.x, animation_time),
+            ui.ctx()
+                .animate_value_with_time(animation_id.with("y"), target.y, animation_time),
+        );
+        if animate {
+            animated - target
         } else {
-            // allocate space where the item would be
-            let (_id, rect) = ui.allocate_space(hovering_item.inner.size());
-            return rect;
+            Vec2::ZERO
         }
     }
 
-    /// Determines the index of the list item that has the closest y position to the current pointer
-    /// position. Returns `None` if there is no pointer position (e.g. touch device).
+    /// Stores the position of the pointer relative to the origin of the dragged item when dragging
+    /// began, if it isn't known yet. `item_rect` is where the item sits in the list.
+    fn init_drag_delta(&mut self, ui: &Ui, item_rect: Rect) {
+        if self.drag_delta.is_some() {
+            return;
+        }
+        if let Some(press_origin) = ui.input(|i| i.pointer.press_origin()) {
+            self.drag_delta = Some(item_rect.min - press_origin);
+        }
+    }
+
+    /// Determines the index of the list item whose leading edge is closest to the leading edge of
+    /// the dragged item, measured along the main axis of the `ui` layout. This respects the layout
+    /// direction, so lists laid out left-to-right, right-to-left, top-down or bottom-up are all
+    /// sorted in the order the items appear. If the layout wraps (e.g. `ui.horizontal_wrapped`),
+    /// the closest row is found first and the index is determined within that row. The returned
+    /// index is always a flat index into the list. Returns `None` if there is no pointer position
+    /// (e.g. the pointer left the window).
     fn determine_hovering_index(
         &self,
         ui: &Ui,
-        list_len: usize,
-        item_rects: Vec<(usize, Rect)>,
+        item_rects: &[(usize, Rect)],
+        items_len: usize,
     ) -> Option<usize> {
+        let direction = ui.layout().main_dir();
+
         // pointer position
-        let hover_pos = ui.input(|i| i.pointer.hover_pos());
-        if let Some(pointer_pos) = hover_pos {
-            let pointer_pos = if let Some(delta) = self.drag_delta {
-                pointer_pos + delta
-            } else {
-                pointer_pos
-            };
+        let pointer_pos = ui.input(|i| i.pointer.interact_pos())?;
 
-            // find the closest entry to the pointer position
-            // (absolute y distance to top of entry, new entry index, old entry index, entry rect)
-            let mut closest: Option<(f32, usize, usize, Rect)> = None;
-            let _hovering = item_rects.into_iter().enumerate().for_each(
-                |(new_idx, (entry_idx, entry_rect))| {
-                    let entry_dist = (entry_rect.top() - pointer_pos.y).abs(); // todo use center().y instead???
-                    let val = (entry_dist, new_idx, entry_idx, entry_rect);
-
-                    if let Some((closest_dist, ..)) = closest {
-                        if closest_dist > entry_dist {
-                            closest = Some(val)
-                        }
-                    } else {
-                        closest = Some(val)
-                    }
-                },
-            );
+        if item_rects.is_empty() {
+            // empty list -> drop at the start
+            return Some(0);
+        }
 
-            if let Some((_dist, new_idx, _original_idx, rect)) = closest {
-                // determine hovering index
-                let mut hovering_idx = if pointer_pos.y > rect.center().y {
-                    new_idx + 1
-                } else {
-                    new_idx
-                };
+        if self.swapping() {
+            // the target is the item under the pointer, or the closest one if the pointer is in
+            // between items. the dragged item and the target trade places in the preview, so the
+            // position of the hovered rect is the index of the item originally drawn there.
+            let (hovering_idx, _) = item_rects.iter().enumerate().min_by(|(_, a), (_, b)| {
+                let dist_a = a.1.distance_sq_to_pos(pointer_pos);
+                let dist_b = b.1.distance_sq_to_pos(pointer_pos);
+                dist_a.total_cmp(&dist_b)
+            })?;
+            return Some(hovering_idx).filter(|target| self.can_drop_at(*target));
+        }
 
-                if let Some(DragIndices {
-                    source: source_idx, ..
-                }) = self.drag_indices
-                {
-                    // account for source being removed
-                    if source_idx < hovering_idx && hovering_idx < list_len {
-                        hovering_idx += 1;
-                    }
-                }
+        // rect of the dragged item as it hovers under the pointer
+        let dragged_size = self
+            .drag_indices
+            .and_then(|drag_indices| {
+                item_rects
+                    .iter()
+                    .find(|(entry_idx, _)| *entry_idx == drag_indices.source)
+            })
+            .map_or(Vec2::ZERO, |(_, rect)| rect.size());
+        let dragged_rect = Rect::from_min_size(
+            pointer_pos + self.drag_delta.unwrap_or_default(),
+            dragged_size,
+        );
+        let hovering_idx =
+            hovered_position(direction, ui.layout().main_wrap(), dragged_rect, item_rects)?;
+
+        let dragged = self.dragged_indices();
+        let hovering_idx = unshifted_target(hovering_idx, item_rects, &dragged, items_len);
+
+        // skip targets the dragged items can't be dropped at
+        Some(hovering_idx).filter(|target| self.can_drop_at(*target))
+    }
 
-                return Some(hovering_idx);
+    /// Indices of the items currently being dragged in the unshifted list.
+    fn dragged_indices(&self) -> Vec<usize> {
+        match (&self.multi_drag, self.drag_indices) {
+            (Some(multi_drag), _) => multi_drag.sources.clone(),
+            (None, Some(drag_indices)) => vec![drag_indices.source],
+            (None, None) => Vec::new(),
+        }
+    }
+
+    fn can_drag_item(&self, idx: usize) -> bool {
+        self.can_drag.as_ref().is_none_or(|can_drag| can_drag(idx))
+    }
+
+    /// Whether a single item of this list is being dragged and will be swapped with its target, see
+    /// [`DropBehavior::Swap`].
+    fn swapping(&self) -> bool {
+        let dragged_in = self.incoming.is_some() || self.foreign_drop.is_some();
+        self.drop_behavior == DropBehavior::Swap && self.multi_drag.is_none() && !dragged_in
+    }
+
+    /// Whether the dragged items can be dropped at `target`, see [`DragDropUi::set_can_drop`].
+    /// Dropping a single item where it already is is always allowed.
+    fn can_drop_at(&self, target: usize) -> bool {
+        let Some(can_drop) = &self.can_drop else {
+            return true;
+        };
+        let dragged = self.dragged_indices();
+        let dragged_in = self.incoming.is_some() || self.foreign_drop.is_some();
+        if let ([source], false) = (dragged.as_slice(), dragged_in) {
+            let unchanged = if self.swapping() {
+                target == *source
+            } else {
+                target == *source || target == source + 1
+            };
+            if unchanged {
+                return true;
             }
         }
-        return None;
+        dragged.iter().all(|source| can_drop(*source, target))
+    }
+
+    /// Shows whether the dragged item can be dropped at the hovered target. `not_allowed` is set if
+    /// the pointer is over the list, but the target it hovers isn't allowed.
+    fn set_drop_not_allowed(&mut self, ui: &Ui, not_allowed: bool) {
+        self.drop_not_allowed = not_allowed;
+        if not_allowed {
+            ui.ctx().set_cursor_icon(CursorIcon::NotAllowed);
+        }
+    }
+
+    /// Checks whether the item dragged with the pointer would be copied if dropped now, and shows it
+    /// with the cursor while `hovering_target` is set. See [`DragDropUi::copy_modifiers`].
+    fn update_copying(&mut self, ui: &Ui, hovering_target: bool) {
+        let copy_modifiers = self.copy_modifiers;
+        let copy_held = ui.input(|i| {
+            let modifiers = i.modifiers;
+            (copy_modifiers.alt && modifiers.alt)
+                || (copy_modifiers.ctrl && modifiers.ctrl)
+                || (copy_modifiers.shift && modifiers.shift)
+                || (copy_modifiers.mac_cmd && modifiers.mac_cmd)
+                || (copy_modifiers.command && modifiers.command)
+        });
+        // items of source-only lists are always copied
+        self.copying = self.source_only
+            || (copy_held
+                && self.keyboard_drag.is_none()
+                && self.multi_drag.is_none()
+                && !self.swapping());
+        if self.copying && hovering_target {
+            ui.ctx().set_cursor_icon(CursorIcon::Copy);
+        }
     }
 
     fn set_source_index(&mut self, source_idx: usize) {
@@ -366,7 +1763,428 @@ impl Default for DragDropUi {
         Self {
             drag_delta: Default::default(),
             drag_indices: Default::default(),
+            incoming: Default::default(),
+            tree_drag: Default::default(),
+            multi_drag: Default::default(),
+            selection: Default::default(),
+            selection_anchor: Default::default(),
+            clicked_item: Default::default(),
+            keyboard_drag: Default::default(),
+            held_item: None,
+            last_drag_time: f64::NEG_INFINITY,
+            can_drag: None,
+            can_drop: None,
+            drop_not_allowed: false,
+            drop_removes: false,
+            copying: false,
+            copy_preview: None,
+            placeholder_ui: None,
+            has_accepted_payload: None,
+            foreign_drop: None,
             draw_drop_preview: true,
+            id: Default::default(),
+            multi_select: false,
+            drag_whole_item: false,
+            drag_delay: 0.0,
+            drag_threshold: 0.0,
+            accept_files: false,
+            remove_on_drop_outside: false,
+            copy_modifiers: Modifiers::NONE,
+            source_only: false,
+            auto_scroll_margin: 32.0,
+            auto_scroll_speed: 600.0,
+            animation_time: 0.15,
+            drag_style: DragStyle::default(),
+            drop_indicator: DropIndicator::Reflow,
+            drop_behavior: DropBehavior::Insert,
+        }
+    }
+}
+
+/// Moves the shapes of the floating item in `layer_id` with `transform`, rotates them by `tilt`
+/// around `center` and clips them to the screen.
+fn transform_floating_item(
+    ctx: &Context,
+    layer_id: LayerId,
+    transform: TSTransform,
+    tilt: f32,
+    center: Pos2,
+) {
+    // shapes can't be rotated, so they are tessellated into meshes which can
+    let mut tessellator = (tilt != 0.0).then(|| {
+        let (font_tex_size, prepared_discs) = ctx.fonts(|fonts| {
+            let atlas = fonts.texture_atlas();
+            let atlas = atlas.lock();
+            (atlas.size(), atlas.prepared_discs())
+        });
+        let options = ctx.tessellation_options(|options| *options);
+        Tessellator::new(
+            ctx.pixels_per_point(),
+            options,
+            font_tex_size,
+            prepared_discs,
+        )
+    });
+    let rotation = Rot2::from_angle(tilt);
+
+    let screen_rect = ctx.screen_rect();
+    ctx.graphics_mut(|graphics| {
+        let shapes = graphics.entry(layer_id);
+        for idx in 0..shapes.next_idx().0 {
+            shapes.mutate_shape(ShapeIdx(idx), |clipped| {
+                clipped.clip_rect = screen_rect;
+                clipped.shape.transform(transform);
+
+                let Some(tessellator) = &mut tessellator else {
+                    return;
+                };
+                if matches!(clipped.shape, Shape::Callback(_)) {
+                    // custom painting can't be tessellated
+                    return;
+                }
+                let shape = std::mem::replace(&mut clipped.shape, Shape::Noop);
+                let mut mesh = Mesh::with_texture(shape.texture_id());
+                tessellator.tessellate_shape(shape, &mut mesh);
+                mesh.rotate(rotation, center);
+                clipped.shape = Shape::mesh(mesh);
+            });
         }
+    });
+}
+
+/// Paints a line in front of the item at `target`, or behind the last item if there is none, see
+/// [`DropIndicator::Line`]. `item_rects` are in unshifted order.
+fn paint_insertion_line(ui: &Ui, item_rects: &[(usize, Rect)], list_rect: Rect, target: usize) {
+    let (rect, in_front) = match item_rects.iter().find(|(idx, _)| *idx == target) {
+        Some((_, rect)) => (*rect, true),
+        None => match item_rects.last() {
+            Some((_, rect)) => (*rect, false),
+            None => return,
+        },
+    };
+
+    // the line is centered in the spacing between the items. rows of wrapping layouts only
+    // span the item, other lists the whole list.
+    let direction = ui.layout().main_dir();
+    let cross_rect = if ui.layout().main_wrap() {
+        rect
+    } else {
+        list_rect
+    };
+    let stroke = Stroke::new(2.0, ui.visuals().selection.stroke.color);
+    let painter = ui.painter();
+    if direction.is_horizontal() {
+        let gap = ui.spacing().item_spacing.x / 2.0;
+        let x = match (direction, in_front) {
+            (Direction::LeftToRight, true) | (Direction::RightToLeft, false) => rect.left() - gap,
+            _ => rect.right() + gap,
+        };
+        painter.vline(x, cross_rect.y_range(), stroke);
+    } else {
+        let gap = ui.spacing().item_spacing.y / 2.0;
+        let y = match (direction, in_front) {
+            (Direction::TopDown, true) | (Direction::BottomUp, false) => rect.top() - gap,
+            _ => rect.bottom() + gap,
+        };
+        painter.hline(cross_rect.x_range(), y, stroke);
+    }
+}
+
+/// Outlines the item the dragged item will be swapped with, see [`DropBehavior::Swap`].
+fn paint_swap_outline(ui: &Ui, item_rect: Rect) {
+    let stroke = Stroke::new(2.0, ui.visuals().selection.stroke.color);
+    let rounding = ui.visuals().widgets.active.rounding;
+    ui.painter()
+        .rect_stroke(item_rect.expand(2.0), rounding, stroke);
+}
+
+/// Paints `text` in a small circle at the top right corner of the dragged item, e.g. the number of
+/// dragged items.
+fn paint_badge(ui: &Ui, item_rect: Rect, text: String) {
+    let visuals = &ui.visuals().selection;
+    let center = item_rect.right_top();
+    let font_id = egui::TextStyle::Small.resolve(ui.style());
+    let radius = font_id.size * 0.8;
+    ui.painter().circle_filled(center, radius, visuals.bg_fill);
+    ui.painter().text(
+        center,
+        egui::Align2::CENTER_CENTER,
+        text,
+        font_id,
+        visuals.stroke.color,
+    );
+}
+
+/// Adds `add_contents` to `ui` as a single list item. In wrapping layouts the item is allocated with
+/// its size from the previous frame, so that it moves onto the next row like a regular widget would
+/// instead of overflowing the current one.
+fn item_scope<R>(ui: &mut Ui, id: Id, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+    if !ui.layout().main_wrap() {
+        return ui.scope(add_contents);
+    }
+
+    let size_id = id.with("egui_dnd_item_size");
+    let size = ui.data(|d| d.get_temp::<Vec2>(size_id)).unwrap_or_default();
+    let layout = ui.layout().with_main_wrap(false);
+    let response = ui.allocate_ui_with_layout(size, layout, add_contents);
+    ui.data_mut(|d| d.insert_temp(size_id, response.response.rect.size()));
+    response
+}
+
+/// Returns the start and end of `rect` along the main axis of `direction`. The values are signed such
+/// that they increase in the direction items are laid out in, e.g. for [`Direction::RightToLeft`] the
+/// start is the (negated) right edge of the rect.
+fn flow_range(direction: Direction, rect: Rect) -> (f32, f32) {
+    match direction {
+        Direction::LeftToRight => (rect.left(), rect.right()),
+        Direction::RightToLeft => (-rect.right(), -rect.left()),
+        Direction::TopDown => (rect.top(), rect.bottom()),
+        Direction::BottomUp => (-rect.bottom(), -rect.top()),
+    }
+}
+
+/// Maps the flat `position` in `item_rects` the dragged items hover at to the index in the unshifted
+/// list of `items_len` items they will be inserted in front of. The rects are in shifted order, i.e.
+/// the `dragged` items are currently drawn at their preview position, or in unshifted order for
+/// [`DropIndicator::Line`]. Items dragged in from elsewhere have a slot at index `items_len`, which
+/// doesn't count towards the length of the list.
+fn unshifted_target(
+    position: usize,
+    item_rects: &[(usize, Rect)],
+    dragged: &[usize],
+    items_len: usize,
+) -> usize {
+    let preceding_dragged = item_rects[..position.min(item_rects.len())]
+        .iter()
+        .filter(|(entry_idx, _)| dragged.contains(entry_idx))
+        .count();
+    let mut remaining = item_rects
+        .iter()
+        .map(|(entry_idx, _)| *entry_idx)
+        .filter(|entry_idx| !dragged.contains(entry_idx))
+        .collect::<Vec<_>>();
+    remaining.sort_unstable();
+    remaining
+        .get(position - preceding_dragged)
+        .copied()
+        .unwrap_or(items_len)
+}
+
+/// Returns the flat position in `item_rects` that an item hovering at `dragged_rect` would be
+/// inserted at. If the layout `wraps`, the row closest to the dragged item is found first and the
+/// position is determined within that row. Returns `None` if `item_rects` is empty.
+fn hovered_position(
+    direction: Direction,
+    wraps: bool,
+    dragged_rect: Rect,
+    item_rects: &[(usize, Rect)],
+) -> Option<usize> {
+    let (dragged_start, _) = flow_range(direction, dragged_rect);
+    if !wraps {
+        return closest_in_row(direction, dragged_start, item_rects);
+    }
+
+    let dragged_cross = cross_center(direction, dragged_rect);
+    let (row_offset, row) =
+        split_rows(direction, item_rects)
+            .into_iter()
+            .min_by(|(_, row_a), (_, row_b)| {
+                let dist_a = cross_distance(direction, row_a, dragged_cross);
+                let dist_b = cross_distance(direction, row_b, dragged_cross);
+                dist_a.total_cmp(&dist_b)
+            })?;
+    Some(row_offset + closest_in_row(direction, dragged_start, row)?)
+}
+
+/// Returns the index within `row` that the dragged item would be inserted at, by finding the entry
+/// whose start along the main axis is closest to `dragged_start`. Returns `None` if `row` is empty.
+fn closest_in_row(
+    direction: Direction,
+    dragged_start: f32,
+    row: &[(usize, Rect)],
+) -> Option<usize> {
+    // find the closest entry to the dragged item
+    // (absolute distance along the main axis to the start of the entry, new entry index, entry rect)
+    let mut closest: Option<(f32, usize, Rect)> = None;
+    for (new_idx, (_entry_idx, entry_rect)) in row.iter().enumerate() {
+        let (entry_start, _) = flow_range(direction, *entry_rect);
+        let entry_dist = (entry_start - dragged_start).abs();
+
+        match closest {
+            Some((closest_dist, ..)) if closest_dist <= entry_dist => {}
+            _ => closest = Some((entry_dist, new_idx, *entry_rect)),
+        }
+    }
+    let (_dist, new_idx, rect) = closest?;
+
+    let (entry_start, entry_end) = flow_range(direction, rect);
+    if dragged_start > (entry_start + entry_end) / 2.0 {
+        Some(new_idx + 1)
+    } else {
+        Some(new_idx)
+    }
+}
+
+/// Splits the entries of a wrapping layout into rows (or columns for vertical layouts). A new row
+/// starts wherever an entry starts before the previous one along the main axis. Each row is returned
+/// along with the flat index of its first entry.
+fn split_rows(
+    direction: Direction,
+    item_rects: &[(usize, Rect)],
+) -> Vec<(usize, &[(usize, Rect)])> {
+    let mut rows = Vec::new();
+    let mut row_offset = 0;
+    for idx in 1..item_rects.len() {
+        let (prev_start, _) = flow_range(direction, item_rects[idx - 1].1);
+        let (start, _) = flow_range(direction, item_rects[idx].1);
+        if start < prev_start {
+            rows.push((row_offset, &item_rects[row_offset..idx]));
+            row_offset = idx;
+        }
+    }
+    if row_offset < item_rects.len() {
+        rows.push((row_offset, &item_rects[row_offset..]));
+    }
+    rows
+}
+
+/// Center of `rect` along the cross axis of `direction`.
+fn cross_center(direction: Direction, rect: Rect) -> f32 {
+    if direction.is_horizontal() {
+        rect.center().y
+    } else {
+        rect.center().x
+    }
+}
+
+/// Distance along the cross axis of `direction` from `cross_pos` to the span covered by `row`. Zero
+/// if `cross_pos` lies within the row.
+fn cross_distance(direction: Direction, row: &[(usize, Rect)], cross_pos: f32) -> f32 {
+    let (min, max) = row
+        .iter()
+        .map(|(_, rect)| {
+            if direction.is_horizontal() {
+                (rect.top(), rect.bottom())
+            } else {
+                (rect.left(), rect.right())
+            }
+        })
+        .fold(
+            (f32::INFINITY, f32::NEG_INFINITY),
+            |(min, max), (start, end)| (min.min(start), max.max(end)),
+        );
+    if cross_pos < min {
+        min - cross_pos
+    } else if cross_pos > max {
+        cross_pos - max
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::{pos2, vec2};
+
+    const DIRECTIONS: [Direction; 4] = [
+        Direction::LeftToRight,
+        Direction::RightToLeft,
+        Direction::TopDown,
+        Direction::BottomUp,
+    ];
+
+    /// A 10x10 rect starting at `main` along the main axis of `direction`, in the `row`th row of
+    /// 12 points each
+    fn rect(direction: Direction, main: f32, row: usize) -> Rect {
+        let cross = row as f32 * 12.0;
+        let min = match direction {
+            Direction::LeftToRight => pos2(main, cross),
+            Direction::RightToLeft => pos2(-main - 10.0, cross),
+            Direction::TopDown => pos2(cross, main),
+            Direction::BottomUp => pos2(cross, -main - 10.0),
+        };
+        Rect::from_min_size(min, vec2(10.0, 10.0))
+    }
+
+    /// Two rows of three items with 2 points of spacing, in the order they are laid out in
+    fn grid(direction: Direction) -> Vec<(usize, Rect)> {
+        (0..6)
+            .map(|idx| (idx, rect(direction, (idx % 3) as f32 * 12.0, idx / 3)))
+            .collect()
+    }
+
+    #[test]
+    fn unshifted_target_of_items_dragged_in_is_at_most_the_list_length() {
+        let item_rects = grid(Direction::TopDown)[..3].to_vec();
+        // the slot of the item dragged in is drawn in front of the last item
+        let mut shifted = item_rects.clone();
+        shifted.insert(2, (3, rect(Direction::TopDown, 36.0, 0)));
+        for (position, target) in [(0, 0), (1, 1), (2, 2), (3, 2), (4, 3)] {
+            assert_eq!(unshifted_target(position, &shifted, &[3], 3), target);
+        }
+        // `DropIndicator::Line` draws no slot
+        assert_eq!(unshifted_target(3, &item_rects, &[3], 3), 3);
+    }
+
+    #[test]
+    fn unshifted_target_skips_the_dragged_item() {
+        // item 0 is drawn at its preview position behind item 1
+        let shifted = vec![
+            (1, rect(Direction::TopDown, 0.0, 0)),
+            (0, rect(Direction::TopDown, 12.0, 0)),
+            (2, rect(Direction::TopDown, 24.0, 0)),
+        ];
+        for (position, target) in [(0, 1), (1, 2), (2, 2), (3, 3)] {
+            assert_eq!(unshifted_target(position, &shifted, &[0], 3), target);
+        }
+    }
+
+    #[test]
+    fn split_rows_starts_a_row_where_the_flow_goes_back() {
+        for direction in DIRECTIONS {
+            let item_rects = grid(direction);
+            let rows = split_rows(direction, &item_rects)
+                .iter()
+                .map(|(offset, row)| (*offset, row.len()))
+                .collect::<Vec<_>>();
+            assert_eq!(rows, vec![(0, 3), (3, 3)], "{direction:?}");
+        }
+        assert!(split_rows(Direction::LeftToRight, &[]).is_empty());
+    }
+
+    #[test]
+    fn hovered_position_is_a_flat_index() {
+        for direction in DIRECTIONS {
+            let item_rects = grid(direction);
+            let position = |main, row| {
+                hovered_position(direction, true, rect(direction, main, row), &item_rects)
+            };
+            assert_eq!(position(-2.0, 0), Some(0), "{direction:?}");
+            assert_eq!(position(4.0, 0), Some(0), "{direction:?}");
+            assert_eq!(position(8.0, 0), Some(1), "{direction:?}");
+            assert_eq!(position(30.0, 0), Some(3), "{direction:?}");
+            assert_eq!(position(-2.0, 1), Some(3), "{direction:?}");
+            assert_eq!(position(8.0, 1), Some(4), "{direction:?}");
+            assert_eq!(position(30.0, 1), Some(6), "{direction:?}");
+        }
+    }
+
+    #[test]
+    fn hovered_position_without_wrapping() {
+        for direction in DIRECTIONS {
+            let item_rects = grid(direction)[..3].to_vec();
+            let position =
+                |main| hovered_position(direction, false, rect(direction, main, 0), &item_rects);
+            assert_eq!(position(-2.0), Some(0), "{direction:?}");
+            assert_eq!(position(14.0), Some(1), "{direction:?}");
+            assert_eq!(position(20.0), Some(2), "{direction:?}");
+            assert_eq!(position(40.0), Some(3), "{direction:?}");
+        }
+        let dragged = rect(Direction::TopDown, 0.0, 0);
+        assert_eq!(
+            hovered_position(Direction::TopDown, false, dragged, &[]),
+            None
+        );
     }
 }
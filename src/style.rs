@@ -0,0 +1,57 @@
+use egui::{Frame, Style};
+use epaint::Shadow;
+
+/// Looks of the floating item while it's dragged, see [`crate::DragDropUi::drag_style`]. The
+/// default draws the item as it is in the list.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DragStyle {
+    /// Opacity of the dragged item, from 0 (invisible) to 1 (opaque)
+    pub opacity: f32,
+    /// Scale of the dragged item around its center, e.g. 1.05 to enlarge it slightly
+    pub scale: f32,
+    /// Clockwise rotation of the dragged item around its center in radians
+    pub tilt: f32,
+    /// Shadow cast by the dragged item, drawn with the rounding of `frame`
+    pub shadow: Shadow,
+    /// Frame drawn behind the dragged item. Its shadow is replaced by `shadow`.
+    pub frame: Option<Frame>,
+}
+
+impl DragStyle {
+    /// Makes the dragged item look lifted off the list, like a popup of `style` that is slightly
+    /// enlarged and tilted
+    pub fn lifted(style: &Style) -> Self {
+        Self {
+            opacity: 0.9,
+            scale: 1.05,
+            tilt: 0.03,
+            shadow: style.visuals.popup_shadow,
+            frame: Some(Frame::popup(style)),
+        }
+    }
+}
+
+impl Default for DragStyle {
+    fn default() -> Self {
+        Self {
+            opacity: 1.0,
+            scale: 1.0,
+            tilt: 0.0,
+            shadow: Shadow::NONE,
+            frame: None,
+        }
+    }
+}
+
+/// How a list shows where the dragged item will be dropped, see
+/// [`crate::DragDropUi::drop_indicator`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DropIndicator {
+    /// The items move to make room for the dragged item at its target
+    #[default]
+    Reflow,
+    /// The items stay in place and a line is painted where the dragged item will be inserted, or
+    /// the item it will be swapped with is outlined, see [`crate::DropBehavior::Swap`]. The
+    /// dragged item is drawn in its slot, dimmed if `dim_dragged` is set.
+    Line { dim_dragged: bool },
+}
@@ -0,0 +1,204 @@
+use crate::utils::shift_slice;
+use crate::{DragDropResponse, DragDropUi, DragIndices, DragableItem};
+use egui::{self, Button, Id, Key, Pos2, Rect, ScrollArea, Ui, WidgetText};
+use std::hash::Hash;
+
+/// A row of reorderable tabs, built on [`DragDropUi::list_ui`]. Tabs are activated by clicking
+/// and dragged by clicking anywhere on them. Tabs released in the bar past the last tab are moved
+/// to the end. If there are more tabs than fit, the bar can be scrolled, and it scrolls by itself
+/// while a tab is dragged near its edges.
+///
+/// The active tab is tracked by [`DragableItem::drag_id`], so it stays active when the tabs are
+/// reordered. Store the bar in your app state like a [`DragDropUi`].
+///
+/// ```rust,no_run
+/// # use egui_dnd::TabBar;
+/// # fn tabs_ui(ui: &mut egui::Ui, tab_bar: &mut TabBar, tabs: &mut Vec<String>) {
+/// let response = tab_bar.ui(ui, tabs, |tab| tab.clone());
+/// if let Some(index) = response.closed {
+///     tabs.remove(index);
+/// }
+/// if let Some(index) = tab_bar.active_index(tabs) {
+///     ui.label(format!("Contents of {}", tabs[index]));
+/// }
+/// # }
+/// ```
+pub struct TabBar {
+    id: Id,
+    /// Id of the active tab, see [`TabBar::active`]
+    active: Option<Id>,
+    /// Whether the active tab should be scrolled into view, see [`TabBar::set_active`]
+    scroll_to_active: bool,
+    /// Rect of the bar in the last frame, releasing a tab within it doesn't tear it off
+    bar_rect: Rect,
+    /// The list of tabs, e.g. to change the [`DragDropUi::drag_style`] of dragged tabs
+    pub dnd: DragDropUi,
+    /// Shows a close button on every tab, see [`TabBarResponse::closed`]
+    pub closable: bool,
+    /// Allows tearing tabs off by releasing them outside of the bar, e.g. to open them in a window.
+    /// See [`TabBarResponse::torn_off`].
+    pub tear_off: bool,
+}
+
+/// What happened to the tabs of a [`TabBar`] this frame
+#[derive(Clone, Default)]
+pub struct TabBarResponse {
+    /// A tab was dragged to another position. The bar already moved it in `tabs`, this is for
+    /// keeping other data in the same order.
+    pub reordered: Option<DragIndices>,
+    /// Index of the tab whose close button was clicked. Remove it from `tabs` to close it.
+    pub closed: Option<usize>,
+    /// A tab was released outside of the bar, see [`TabBar::tear_off`]. Remove it from `tabs`
+    /// to tear it off.
+    pub torn_off: Option<TornOffTab>,
+}
+
+/// A tab released outside of its [`TabBar`], see [`TabBar::tear_off`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TornOffTab {
+    /// Index of the tab in `tabs`
+    pub index: usize,
+    /// Where the tab was released, e.g. to open a window there
+    pub pointer_pos: Pos2,
+}
+
+impl TabBar {
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id: Id::new(id_salt).with("egui_dnd_tab_bar"),
+            active: None,
+            scroll_to_active: false,
+            bar_rect: Rect::NOTHING,
+            // tabs keep their clicks, since they are only dragged once the pointer moved a bit
+            dnd: DragDropUi {
+                drag_whole_item: true,
+                ..Default::default()
+            },
+            closable: true,
+            tear_off: false,
+        }
+    }
+
+    /// Id of the active tab. Defaults to the first tab, and if the active tab is closed or torn
+    /// off its neighbor is activated.
+    pub fn active(&self) -> Option<Id> {
+        self.active
+    }
+
+    /// Index of the active tab in `tabs`, see [`TabBar::active`]
+    pub fn active_index<T: DragableItem>(&self, tabs: &[T]) -> Option<usize> {
+        let active = self.active?;
+        tabs.iter().position(|tab| tab.drag_id() == active)
+    }
+
+    /// Activates the tab with the `id` and scrolls it into view, e.g. after opening a new tab
+    pub fn set_active(&mut self, id: Id) {
+        self.active = Some(id);
+        self.scroll_to_active = true;
+    }
+
+    /// Draws the `tabs` with the text `title` returns for each of them, and moves the tabs that
+    /// are dragged to another position. Closing and tearing off tabs is left to the caller, see
+    /// [`TabBarResponse`].
+    pub fn ui<T: DragableItem, R: Into<WidgetText>>(
+        &mut self,
+        ui: &mut Ui,
+        tabs: &mut [T],
+        mut title: impl FnMut(&T) -> R,
+    ) -> TabBarResponse {
+        // the active tab may have been removed, e.g. by closing it
+        if self.active_index(tabs).is_none() {
+            self.active = tabs.first().map(DragableItem::drag_id);
+        }
+        // the whole bar is where tabs are dropped, not only the tabs in it
+        let pointer_pos = ui.input(|i| i.pointer.interact_pos());
+        let in_bar = |rect: Rect| pointer_pos.is_some_and(|pos| rect.contains(pos));
+        self.dnd.remove_on_drop_outside = self.tear_off && !in_bar(self.bar_rect);
+
+        let active = self.active;
+        let closable = self.closable;
+        let scroll_to_active = std::mem::take(&mut self.scroll_to_active);
+        let mut clicked = None;
+        let mut closed = None;
+        let dnd = &mut self.dnd;
+        let drag_response = ScrollArea::horizontal()
+            .id_salt(self.id)
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    let ctx = ui.ctx().clone();
+                    dnd.list_ui(&ctx, ui, tabs.iter(), |ui, handle, index, tab| {
+                        handle.ui(ui, tab, |ui| {
+                            let is_active = active == Some(tab.drag_id());
+                            let response = ui.selectable_label(is_active, title(tab));
+                            if response.clicked() {
+                                clicked = Some(tab.drag_id());
+                                response.scroll_to_me(None);
+                            } else if is_active && scroll_to_active {
+                                response.scroll_to_me(None);
+                            }
+                            if closable
+                                && ui
+                                    .add(Button::new("×").small().frame(false))
+                                    .on_hover_text("Close")
+                                    .clicked()
+                            {
+                                closed = Some(index);
+                            }
+                        });
+                    })
+                })
+                .inner
+            });
+
+        if clicked.is_some() {
+            self.active = clicked;
+        }
+        let mut response = TabBarResponse {
+            closed,
+            ..Default::default()
+        };
+        // the scroll area takes the whole width, but is only as tall as the tabs
+        let mut bar_rect = drag_response.inner_rect;
+        bar_rect.set_height(drag_response.content_size.y.min(bar_rect.height()));
+        self.bar_rect = bar_rect;
+        let dropped = ui.input(|i| i.pointer.any_released() && !i.key_pressed(Key::Escape));
+        let drag_indices = match drag_response.inner {
+            DragDropResponse::Completed(drag_indices) => Some(drag_indices),
+            // released in the bar, but past the last tab
+            DragDropResponse::Removed(source)
+            | DragDropResponse::Cancelled(DragIndices { source, .. })
+                if dropped && in_bar(bar_rect) =>
+            {
+                Some(DragIndices {
+                    source,
+                    target: tabs.len(),
+                })
+            }
+            DragDropResponse::Removed(index) => {
+                // the pointer is only missing if it left the window at the same time
+                let pointer_pos = pointer_pos.unwrap_or(bar_rect.center());
+                response.torn_off = Some(TornOffTab { index, pointer_pos });
+                None
+            }
+            _ => None,
+        };
+        if let Some(drag_indices) = drag_indices {
+            response.reordered = shift_slice(drag_indices.source, drag_indices.target, tabs)
+                .ok()
+                .map(|_| drag_indices);
+        }
+
+        // keep a tab active once the closed or torn off tab is removed
+        let removed = closed.or(response.torn_off.map(|torn_off| torn_off.index));
+        let active_removed =
+            removed.filter(|index| tabs.get(*index).map(DragableItem::drag_id) == self.active);
+        if let Some(index) = active_removed {
+            let neighbor = tabs
+                .get(index + 1)
+                .or(index.checked_sub(1).map(|i| &tabs[i]));
+            self.active = neighbor.map(DragableItem::drag_id);
+        }
+        response
+    }
+}
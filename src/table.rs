@@ -0,0 +1,229 @@
+use crate::{
+    handle::DragHandle,
+    style::DropIndicator,
+    virtual_list::{copied_index, shifted_index, swapped_index, RowLayout},
+    DragDropResponse, DragDropUi, DragableItem, PlaceholderItem,
+};
+use egui::{self, Align, Context, Layout, Rect, Sense, Ui, UiBuilder, Vec2};
+use egui_extras::TableBody;
+
+impl DragDropUi {
+    /// Draws `items` as the rows of an [`egui_extras::Table`], so that they can be sorted like the
+    /// items of [`DragDropUi::list_ui`]. Call it with the [`TableBody`] passed to
+    /// [`egui_extras::TableBuilder::body`]. Like [`TableBody::rows`], only the visible rows are
+    /// drawn and every row has to be `row_height` tall.
+    ///
+    /// `cell_ui(ui, index, column, item)` draws a cell of the item at `index`. The cells of
+    /// `handle_column` are the drag handles of their rows. While a row is dragged it floats under
+    /// the pointer with all its cells, and the other rows make room for it. Returns the same
+    /// responses as [`DragDropUi::virtual_list_ui`], which also shares its limitations: rows can't be
+    /// dragged between tables, and neither [`DragDropUi::multi_select`] nor
+    /// [`DragDropUi::drag_whole_item`] are supported. Rows move without animations.
+    ///
+    /// ```rust,no_run
+    /// # use egui_dnd::{utils::shift_slice, DragDropResponse, DragDropUi};
+    /// # use egui_extras::{Column, TableBuilder};
+    /// # fn table_ui(ui: &mut egui::Ui, dnd: &mut DragDropUi, items: &mut Vec<String>) {
+    /// let ctx = ui.ctx().clone();
+    /// TableBuilder::new(ui)
+    ///     .column(Column::auto())
+    ///     .column(Column::remainder())
+    ///     .body(|body| {
+    ///         let response = dnd.table_ui(&ctx, body, 18.0, items, 0, |ui, index, column, item| {
+    ///             match column {
+    ///                 0 => ui.label("grab"),
+    ///                 _ => ui.label(format!("{index}: {item}")),
+    ///             };
+    ///         });
+    ///         if let DragDropResponse::Completed(drag_indices) = response {
+    ///             shift_slice(drag_indices.source, drag_indices.target, items).unwrap();
+    ///         }
+    ///     });
+    /// # }
+    /// ```
+    pub fn table_ui<T: DragableItem>(
+        &mut self,
+        context: &Context,
+        mut body: TableBody,
+        row_height: f32,
+        items: &[T],
+        handle_column: usize,
+        mut cell_ui: impl FnMut(&mut Ui, usize, usize, &T),
+    ) -> DragDropResponse {
+        if let Some(drag_indices) = self.drag_indices {
+            if drag_indices.source >= items.len() || drag_indices.target > items.len() {
+                // current drag indices are busted!
+                self.reset_drag();
+            }
+        }
+
+        // the rows are laid out by the table, everything else is drawn to a ui covering the visible
+        // part of the body
+        let visible_rect = body.max_rect();
+        let widths = body.widths().to_vec();
+        let mut ui = body.ui_mut().new_child(
+            UiBuilder::new()
+                .max_rect(visible_rect)
+                .layout(Layout::top_down(Align::Min)),
+        );
+
+        // draw the visible rows, shifted according to previous hover state unless the target is
+        // shown with a line. a copy is previewed in an extra row at its target.
+        let reflow = self.drop_indicator == DropIndicator::Reflow;
+        let swap = self.swapping();
+        let preview = self.drag_indices.filter(|_| reflow);
+        let copy_preview = preview.filter(|_| self.copy_preview.is_some());
+        let copy_slot = copy_preview.map(|drag_indices| drag_indices.target);
+        let rows = RowLayout::Uniform {
+            height: row_height,
+            spacing: ui.spacing().item_spacing.y,
+            len: items.len() + usize::from(copy_slot.is_some()),
+        };
+        let custom_placeholder = self.placeholder_ui.is_some() && copy_preview.is_none();
+        let mut content_top = None;
+        let mut visible_rows = None;
+        let mut cell_layout = Layout::default();
+        let mut focused_item = None;
+        let mut dragged_row_rect = None;
+        body.rows(row_height, rows.len(), |mut row| {
+            let row_idx = row.index();
+            let idx = match copy_preview {
+                Some(drag_indices) => copied_index(row_idx, drag_indices),
+                None if swap => swapped_index(row_idx, preview),
+                None => shifted_index(row_idx, preview),
+            };
+            let item = &items[idx];
+            let id = item.drag_id();
+
+            // leave the row of the copy empty
+            if copy_slot == Some(row_idx) {
+                let mut row_rect = Rect::NOTHING;
+                for _ in 0..widths.len() {
+                    row.col(|ui| row_rect = row_rect.union(ui.max_rect()));
+                }
+                content_top.get_or_insert(row_rect.top() - rows.top(row_idx));
+                visible_rows.get_or_insert(row_idx..row_idx).end = row_idx + 1;
+                let item = PlaceholderItem::Item {
+                    index: idx,
+                    item_id: id,
+                };
+                self.draw_custom_placeholder(&mut ui, item, row_rect);
+                return;
+            }
+            let is_being_dragged = context.is_being_dragged(id);
+            let draggable = self.can_drag_item(idx);
+
+            // highlight the item picked up with the keyboard
+            row.set_selected(self.keyboard_drag == Some(id));
+            let mut row_rect = Rect::NOTHING;
+            for column in 0..widths.len() {
+                row.col(|ui| {
+                    row_rect = row_rect.union(ui.max_rect());
+                    cell_layout = *ui.layout();
+                    if is_being_dragged {
+                        self.style_placeholder(ui, custom_placeholder);
+                        cell_ui(ui, idx, column, item);
+                    } else if column == handle_column {
+                        let handle = DragHandle {
+                            state: self,
+                            placeholder: false,
+                            draggable,
+                            payload: None,
+                        };
+                        handle.ui(ui, item, |ui| cell_ui(ui, idx, column, item));
+                    } else {
+                        cell_ui(ui, idx, column, item);
+                    }
+                });
+            }
+            content_top.get_or_insert(row_rect.top() - rows.top(row_idx));
+            visible_rows.get_or_insert(row_idx..row_idx).end = row_idx + 1;
+
+            if is_being_dragged {
+                self.set_source_index(idx);
+                dragged_row_rect = Some(row_rect);
+                if custom_placeholder {
+                    let item = PlaceholderItem::Item {
+                        index: idx,
+                        item_id: id,
+                    };
+                    self.draw_custom_placeholder(&mut ui, item, row_rect);
+                }
+                self.paint_drop_not_allowed(&ui, row_rect);
+            } else {
+                self.paint_armed_outline(&ui, id, row_rect);
+            }
+
+            if context.memory(|m| m.has_focus(id)) {
+                focused_item = Some((idx, id));
+            }
+        });
+        let Some(content_top) = content_top else {
+            return DragDropResponse::NoDrag;
+        };
+        let content_rect = Rect::from_min_size(
+            egui::pos2(visible_rect.left(), content_top),
+            Vec2::new(visible_rect.width(), rows.content_height()),
+        );
+        let row_rect = |row: usize| {
+            Rect::from_min_size(
+                content_rect.min + Vec2::new(0.0, rows.top(row)),
+                Vec2::new(content_rect.width(), rows.height(row)),
+            )
+        };
+
+        // the dragged row floats under the pointer with all its cells. its row may have been
+        // scrolled out of view.
+        let dragged_idx = self
+            .drag_indices
+            .map(|drag_indices| drag_indices.source)
+            .filter(|idx| context.is_being_dragged(items[*idx].drag_id()));
+        if let Some(idx) = dragged_idx {
+            let item = &items[idx];
+            if let Some(rect) = dragged_row_rect {
+                self.init_drag_delta(&ui, rect);
+            }
+            self.draw_floating_item(&ui, item.drag_id(), |ui, handle| {
+                let mut handle = Some(handle);
+                ui.horizontal(|ui| {
+                    for (column, width) in widths.iter().enumerate() {
+                        let size = Vec2::new(*width, row_height);
+                        ui.allocate_ui_with_layout(size, cell_layout, |ui| {
+                            ui.set_min_size(size);
+                            let handle = handle.take_if(|_| column == handle_column);
+                            match handle {
+                                Some(handle) => {
+                                    handle.ui(ui, item, |ui| cell_ui(ui, idx, column, item));
+                                }
+                                None => cell_ui(ui, idx, column, item),
+                            }
+                        });
+                    }
+                });
+            });
+        }
+
+        // without reflow the item picked up with the keyboard stays in its row, which may have been
+        // scrolled out of view. its handle still takes the focus there, so that it keeps it.
+        if let (Some(drag_indices), Some(grabbed_id)) = (self.drag_indices, self.keyboard_drag) {
+            let idx = drag_indices.source;
+            let visible = visible_rows
+                .as_ref()
+                .is_some_and(|rows| rows.contains(&idx));
+            if !reflow && !visible && idx < items.len() {
+                ui.interact(row_rect(idx), grabbed_id, Sense::focusable_noninteractive());
+                if context.memory(|m| m.has_focus(grabbed_id)) {
+                    focused_item = Some((idx, grabbed_id));
+                }
+            }
+        }
+
+        self.update_row_drag(
+            &ui,
+            content_rect,
+            &rows,
+            focused_item,
+            dragged_idx.map(|idx| (idx, items[idx].drag_id())),
+        )
+    }
+}
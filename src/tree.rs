@@ -0,0 +1,351 @@
+use crate::{handle::DragHandle, DragDropUi, DragableItem};
+use egui::{self, collapsing_header::CollapsingState, Context, Key, Pos2, Rect, Stroke, Ui};
+
+/// An item in a tree drawn with [`DragDropUi::tree_ui`].
+pub trait DragableTreeItem: DragableItem + Sized {
+    /// Children of this node, or `None` if the node is a leaf that other nodes can't be dropped into.
+    fn children(&self) -> Option<&[Self]>;
+
+    /// Mutable access to the children of this node, used by [`crate::utils::move_tree_item`].
+    fn children_mut(&mut self) -> Option<&mut Vec<Self>>;
+}
+
+/// Source and target of a dragged tree node. A path is the list of child indices leading from the
+/// root nodes to a node, e.g. `[1, 0]` is the first child of the second root node.
+///
+/// `target` is the path the node should be inserted at, before the source node has been removed
+/// from the tree. See [`crate::utils::move_tree_item`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TreeDragIndices {
+    pub source: Vec<usize>,
+    pub target: Vec<usize>,
+}
+
+#[derive(Clone)]
+pub enum TreeDragDropResponse {
+    NoDrag,
+    CurrentDrag(TreeDragIndices),
+    Completed(TreeDragIndices),
+    /// The drag was cancelled by pressing escape or releasing the node outside of the tree
+    Cancelled(TreeDragIndices),
+}
+
+/// Where a dragged node will be dropped relative to the hovered node
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DropPosition {
+    Before,
+    After,
+    /// First child of an open node
+    FirstChild,
+    /// Last child of the node
+    Inside,
+}
+
+/// A node drawn in the tree
+struct TreeRow {
+    path: Vec<usize>,
+    rect: Rect,
+    /// Number of children, `None` if the node doesn't accept children
+    child_count: Option<usize>,
+    /// Whether the children of the node are shown
+    open: bool,
+}
+
+impl DragDropUi {
+    /// Draws the tree of `roots` and their children to `ui` using `item_ui` for each node. Nodes with
+    /// children can be collapsed. A node can be dropped before or after another node, or inside
+    /// nodes that accept children. Returns the dragging response (to be actioned by the caller).
+    ///
+    /// `item_ui` works like the one of [`DragDropUi::list_ui`], except that it receives the path
+    /// of the node instead of an index.
+    pub fn tree_ui<T: DragableTreeItem>(
+        &mut self,
+        context: &Context,
+        ui: &mut Ui,
+        roots: &[T],
+        mut item_ui: impl FnMut(&mut Ui, DragHandle, &[usize], &T),
+    ) -> TreeDragDropResponse {
+        let mut rows = Vec::new();
+
+        // draw tree nodes
+        let this_tree_is_drop_target = self.tree_drag.is_some();
+        let tree_response = Self::draw_list(ui, this_tree_is_drop_target, |ui| {
+            self.draw_tree_nodes(context, ui, roots, &mut Vec::new(), &mut rows, &mut item_ui);
+        });
+
+        // scroll towards the nodes hidden beyond the edges of a scroll area
+        if self.tree_drag.is_some() && !ui.input(|i| i.pointer.any_released()) {
+            self.auto_scroll(ui, tree_response.rect);
+        }
+
+        // determine target path
+        let tree_hovered_over = ui.rect_contains_pointer(tree_response.rect);
+        let pointer_pos = ui.input(|i| i.pointer.interact_pos());
+        let mut outside_tree = true;
+        if let Some(tree_drag) = &mut self.tree_drag {
+            let target = pointer_pos
+                .filter(|_| tree_hovered_over)
+                .and_then(|pointer_pos| {
+                    determine_tree_target(pointer_pos, &tree_drag.source, &rows)
+                });
+            match target {
+                Some((target, row_idx, position)) => {
+                    paint_drop_indicator(ui, &rows[row_idx], position);
+                    tree_drag.target = target;
+                    outside_tree = false;
+                }
+                // no node being hovered over -> no target
+                None => tree_drag.target = tree_drag.source.clone(),
+            }
+        }
+
+        // return dragging state
+        if let Some(tree_drag) = self.tree_drag.clone() {
+            let (released, escape_pressed) =
+                ui.input(|i| (i.pointer.any_released(), i.key_pressed(Key::Escape)));
+
+            // dragging finished
+            if released || escape_pressed {
+                self.tree_drag = None;
+                self.drag_delta = None;
+                if escape_pressed || outside_tree {
+                    return TreeDragDropResponse::Cancelled(tree_drag);
+                }
+                return TreeDragDropResponse::Completed(tree_drag);
+            }
+
+            // dragging in progress
+            return TreeDragDropResponse::CurrentDrag(tree_drag);
+        }
+        TreeDragDropResponse::NoDrag
+    }
+
+    /// Draws `nodes` and their children recursively, recording the rect of each drawn node in `rows`.
+    /// `path` is the path of the parent of `nodes`.
+    fn draw_tree_nodes<T: DragableTreeItem>(
+        &mut self,
+        context: &Context,
+        ui: &mut Ui,
+        nodes: &[T],
+        path: &mut Vec<usize>,
+        rows: &mut Vec<TreeRow>,
+        item_ui: &mut impl FnMut(&mut Ui, DragHandle, &[usize], &T),
+    ) {
+        for (idx, node) in nodes.iter().enumerate() {
+            path.push(idx);
+            let id = node.drag_id();
+
+            match node.children() {
+                Some(children) => {
+                    let state = CollapsingState::load_with_default_open(
+                        context,
+                        id.with("egui_dnd_tree_node"),
+                        true,
+                    );
+                    let open = state.is_open();
+                    state
+                        .show_header(ui, |ui| {
+                            let rect = self.draw_item(ui, id, None, false, true, |ui, handle| {
+                                item_ui(ui, handle, path, node);
+                            });
+                            rows.push(TreeRow {
+                                path: path.clone(),
+                                rect,
+                                child_count: Some(children.len()),
+                                open,
+                            });
+                        })
+                        .body(|ui| {
+                            self.draw_tree_nodes(context, ui, children, path, rows, item_ui);
+                        });
+                }
+                None => {
+                    ui.horizontal(|ui| {
+                        // line up with the nodes that have a collapse button
+                        ui.add_space(ui.spacing().indent);
+                        let rect = self.draw_item(ui, id, None, false, true, |ui, handle| {
+                            item_ui(ui, handle, path, node);
+                        });
+                        rows.push(TreeRow {
+                            path: path.clone(),
+                            rect,
+                            child_count: None,
+                            open: false,
+                        });
+                    });
+                }
+            }
+
+            // check if this node is being dragged
+            if context.is_being_dragged(id) {
+                let tree_drag = self.tree_drag.get_or_insert_with(|| TreeDragIndices {
+                    source: path.clone(),
+                    target: path.clone(),
+                });
+                tree_drag.source.clone_from(path);
+            }
+
+            path.pop();
+        }
+    }
+}
+
+/// Determines the path the dragged node would be dropped at from the node row under `pointer_pos`.
+/// Returns the target path along with the index of the hovered row and the drop position relative
+/// to it, or `None` if there are no rows or the source node would be dropped inside itself.
+fn determine_tree_target(
+    pointer_pos: Pos2,
+    source: &[usize],
+    rows: &[TreeRow],
+) -> Option<(Vec<usize>, usize, DropPosition)> {
+    // find the row closest to the pointer
+    let vertical_dist = |rect: &Rect| {
+        if pointer_pos.y < rect.top() {
+            rect.top() - pointer_pos.y
+        } else {
+            (pointer_pos.y - rect.bottom()).max(0.0)
+        }
+    };
+    let (row_idx, row) = rows
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| vertical_dist(&a.rect).total_cmp(&vertical_dist(&b.rect)))?;
+
+    // nodes that accept children are split into before/inside/after regions, leaves into before/after
+    let relative_y = (pointer_pos.y - row.rect.top()) / row.rect.height().max(1.0);
+    let position = match row.child_count {
+        Some(_) if relative_y < 0.25 => DropPosition::Before,
+        Some(child_count) if relative_y > 0.75 => {
+            if row.open && child_count > 0 {
+                // the next row is the first child, so dropping below the node puts it there
+                DropPosition::FirstChild
+            } else {
+                DropPosition::After
+            }
+        }
+        Some(_) => DropPosition::Inside,
+        None if relative_y < 0.5 => DropPosition::Before,
+        None => DropPosition::After,
+    };
+
+    let mut target = row.path.clone();
+    match position {
+        DropPosition::Before => {}
+        DropPosition::After => *target.last_mut()? += 1,
+        DropPosition::FirstChild => target.push(0),
+        DropPosition::Inside => target.push(row.child_count.unwrap_or_default()),
+    }
+
+    // a node can't be dropped inside itself
+    if target.len() > source.len() && target.starts_with(source) {
+        return None;
+    }
+
+    Some((target, row_idx, position))
+}
+
+/// Paints a line where the dragged node will be inserted, or a frame around the node it will be
+/// dropped into.
+fn paint_drop_indicator(ui: &Ui, row: &TreeRow, position: DropPosition) {
+    let stroke = Stroke::new(2.0, ui.visuals().selection.stroke.color);
+    let rect = row.rect;
+    match position {
+        DropPosition::Before => {
+            ui.painter().hline(rect.x_range(), rect.top(), stroke);
+        }
+        DropPosition::After => {
+            ui.painter().hline(rect.x_range(), rect.bottom(), stroke);
+        }
+        DropPosition::FirstChild => {
+            let x_range = (rect.left() + ui.spacing().indent)..=rect.right();
+            ui.painter().hline(x_range, rect.bottom(), stroke);
+        }
+        DropPosition::Inside => {
+            let rounding = ui.visuals().widgets.active.rounding;
+            ui.painter().rect_stroke(rect, rounding, stroke);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use egui::{pos2, vec2};
+
+    /// A tree of an open node `[0]` with the leaf `[0, 0]` and the empty node `[0, 1]`, followed by
+    /// the closed node `[1]` with two children and the leaf `[2]`. Each row is 20 points tall.
+    fn rows() -> Vec<TreeRow> {
+        [
+            (vec![0], Some(2), true),
+            (vec![0, 0], None, false),
+            (vec![0, 1], Some(0), true),
+            (vec![1], Some(2), false),
+            (vec![2], None, false),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (path, child_count, open))| TreeRow {
+            path,
+            rect: Rect::from_min_size(pos2(0.0, idx as f32 * 20.0), vec2(100.0, 20.0)),
+            child_count,
+            open,
+        })
+        .collect()
+    }
+
+    /// Target path and drop position when the node at `source` is dragged to `y`
+    fn target(source: &[usize], y: f32) -> Option<(Vec<usize>, DropPosition)> {
+        determine_tree_target(pos2(50.0, y), source, &rows())
+            .map(|(target, _, position)| (target, position))
+    }
+
+    #[test]
+    fn leaves_are_split_into_before_and_after() {
+        assert_eq!(target(&[1], 22.0), Some((vec![0, 0], DropPosition::Before)));
+        assert_eq!(target(&[1], 38.0), Some((vec![0, 1], DropPosition::After)));
+        assert_eq!(target(&[0], 82.0), Some((vec![2], DropPosition::Before)));
+        assert_eq!(target(&[0], 98.0), Some((vec![3], DropPosition::After)));
+    }
+
+    #[test]
+    fn nodes_are_split_into_before_inside_and_after() {
+        assert_eq!(target(&[2], 62.0), Some((vec![1], DropPosition::Before)));
+        assert_eq!(target(&[2], 70.0), Some((vec![1, 2], DropPosition::Inside)));
+        // the children of a closed node aren't shown, so the node is dropped behind it
+        assert_eq!(target(&[2], 78.0), Some((vec![2], DropPosition::After)));
+        // open nodes without children too
+        assert_eq!(target(&[2], 58.0), Some((vec![0, 2], DropPosition::After)));
+        assert_eq!(
+            target(&[2], 50.0),
+            Some((vec![0, 1, 0], DropPosition::Inside))
+        );
+    }
+
+    #[test]
+    fn below_an_open_node_is_its_first_child() {
+        assert_eq!(
+            target(&[2], 18.0),
+            Some((vec![0, 0], DropPosition::FirstChild))
+        );
+    }
+
+    #[test]
+    fn rows_beyond_the_pointer_are_the_closest_row() {
+        assert_eq!(target(&[1], -30.0), Some((vec![0], DropPosition::Before)));
+        assert_eq!(target(&[0], 150.0), Some((vec![3], DropPosition::After)));
+        assert_eq!(determine_tree_target(pos2(50.0, 10.0), &[0], &[]), None);
+    }
+
+    #[test]
+    fn nodes_cant_be_dropped_inside_themselves() {
+        // inside and first child of the dragged node itself
+        assert_eq!(target(&[0], 10.0), None);
+        assert_eq!(target(&[0], 18.0), None);
+        // inside one of its descendants
+        assert_eq!(target(&[0], 50.0), None);
+        assert_eq!(target(&[0], 22.0), None);
+        // before and after itself is fine
+        assert_eq!(target(&[0], 2.0), Some((vec![0], DropPosition::Before)));
+        assert_eq!(target(&[1], 78.0), Some((vec![2], DropPosition::After)));
+    }
+}
@@ -1,3 +1,5 @@
+use crate::tree::DragableTreeItem;
+
 /// Move an item in a sub_slice according to the drag and drop logic.
 ///
 /// Rotates the section of the sub_slice between `source_idx` and `target_idx` such that the item
@@ -8,14 +10,14 @@
 /// # Example
 ///
 /// ```rust
-/// use egui_dnd::utils::shift_vec;
+/// use egui_dnd::utils::shift_slice;
 ///
 /// let mut v = vec![1, 2, 3, 4];
-/// shift_vec(1, 1, &mut v);
+/// shift_slice(1, 1, &mut v).unwrap();
 /// assert_eq!(v, [1, 2, 3, 4]);
-/// shift_vec(0, 2, &mut v);
+/// shift_slice(0, 2, &mut v).unwrap();
 /// assert_eq!(v, [2, 1, 3, 4]);
-/// shift_vec(2, 0, &mut v);
+/// shift_slice(2, 0, &mut v).unwrap();
 /// assert_eq!(v, [3, 2, 1, 4]);
 /// ```
 ///
@@ -39,6 +41,231 @@ pub fn shift_slice<T>(
     Ok(())
 }
 
+/// Move several items in a slice as a block according to the drag and drop logic, e.g. when handling
+/// [`crate::DragDropResponse::CompletedMultiDrag`].
+///
+/// The items at `source_indices` are gathered in their relative order and inserted in front of the
+/// item previously at `target_idx`, or at the end of the slice if `target_idx == len()`. This matches
+/// [`shift_slice`] when moving a single item.
+///
+/// # Example
+///
+/// ```rust
+/// use egui_dnd::utils::shift_slice_many;
+///
+/// let mut v = vec![1, 2, 3, 4, 5];
+/// shift_slice_many(&[0, 2], 4, &mut v).unwrap();
+/// assert_eq!(v, [2, 4, 1, 3, 5]);
+/// shift_slice_many(&[1, 4], 0, &mut v).unwrap();
+/// assert_eq!(v, [4, 5, 2, 1, 3]);
+/// shift_slice_many(&[0, 3], 5, &mut v).unwrap();
+/// assert_eq!(v, [5, 2, 3, 4, 1]);
+/// ```
+///
+/// Returns an error if any of the `source_indices >= len()` or `target_idx > len()`
+pub fn shift_slice_many<T>(
+    source_indices: &[usize],
+    target_idx: usize,
+    to_shift: &mut [T],
+) -> Result<(), ShiftSliceError> {
+    let invalid_source = source_indices
+        .iter()
+        .find(|source_idx| **source_idx >= to_shift.len());
+    if invalid_source.is_some() || target_idx > to_shift.len() {
+        return Err(ShiftSliceError::InvalidIndices {
+            source_idx: invalid_source
+                .or(source_indices.first())
+                .copied()
+                .unwrap_or_default(),
+            target_idx,
+            slice_len: to_shift.len(),
+        });
+    }
+
+    let mut source_indices = source_indices.to_vec();
+    source_indices.sort_unstable();
+    source_indices.dedup();
+    let (before_target, after_target): (Vec<usize>, Vec<usize>) = source_indices
+        .into_iter()
+        .partition(|source_idx| *source_idx < target_idx);
+
+    // move the items in front of the target down to it, starting with the closest one
+    let mut block_start = target_idx;
+    for source_idx in before_target.into_iter().rev() {
+        to_shift[source_idx..block_start].rotate_left(1);
+        block_start -= 1;
+    }
+
+    // move the items behind the target up to the end of the block
+    for (block_end, source_idx) in (target_idx..).zip(after_target) {
+        to_shift[block_end..=source_idx].rotate_right(1);
+    }
+    Ok(())
+}
+
+/// Swap two items in a slice, e.g. when handling [`crate::DragDropResponse::Completed`] of a list
+/// with [`crate::DropBehavior::Swap`].
+///
+/// The item previously at `source_idx` ends up at `target_idx` and vice versa, the items between
+/// them stay where they are.
+///
+/// # Example
+///
+/// ```rust
+/// use egui_dnd::utils::swap_slice;
+///
+/// let mut v = vec![1, 2, 3, 4];
+/// swap_slice(1, 1, &mut v).unwrap();
+/// assert_eq!(v, [1, 2, 3, 4]);
+/// swap_slice(0, 2, &mut v).unwrap();
+/// assert_eq!(v, [3, 2, 1, 4]);
+/// swap_slice(3, 1, &mut v).unwrap();
+/// assert_eq!(v, [3, 4, 1, 2]);
+/// ```
+///
+/// Returns an error if `source_idx >= len()` or `target_idx >= len()`
+pub fn swap_slice<T>(
+    source_idx: usize,
+    target_idx: usize,
+    to_swap: &mut [T],
+) -> Result<(), ShiftSliceError> {
+    if source_idx >= to_swap.len() || target_idx >= to_swap.len() {
+        return Err(ShiftSliceError::InvalidIndices {
+            source_idx,
+            target_idx,
+            slice_len: to_swap.len(),
+        });
+    }
+    to_swap.swap(source_idx, target_idx);
+    Ok(())
+}
+
+/// Move an item from one list to another according to the drag and drop logic, e.g. when handling
+/// [`crate::DragDropResponse::CompletedTransfer`].
+///
+/// Removes the item at `source_idx` from `source` and inserts it into `target` at `target_idx`.
+///
+/// # Example
+///
+/// ```rust
+/// use egui_dnd::utils::transfer_item;
+///
+/// let mut todo = vec![1, 2, 3];
+/// let mut done = vec![4, 5];
+/// transfer_item(1, 0, &mut todo, &mut done).unwrap();
+/// assert_eq!(todo, [1, 3]);
+/// assert_eq!(done, [2, 4, 5]);
+/// transfer_item(0, 3, &mut todo, &mut done).unwrap();
+/// assert_eq!(todo, [3]);
+/// assert_eq!(done, [2, 4, 5, 1]);
+/// ```
+///
+/// Returns an error if `source_idx >= source.len()` or `target_idx > target.len()`
+pub fn transfer_item<T>(
+    source_idx: usize,
+    target_idx: usize,
+    source: &mut Vec<T>,
+    target: &mut Vec<T>,
+) -> Result<(), TransferItemError> {
+    if source_idx >= source.len() || target_idx > target.len() {
+        return Err(TransferItemError::InvalidIndices {
+            source_idx,
+            target_idx,
+            source_len: source.len(),
+            target_len: target.len(),
+        });
+    }
+    let item = source.remove(source_idx);
+    target.insert(target_idx, item);
+    Ok(())
+}
+
+/// Move a node in a tree according to the drag and drop logic of [`crate::DragDropUi::tree_ui`].
+///
+/// Removes the node at the `source` path and inserts it at the `target` path, where `target` refers
+/// to the tree before the source node was removed. This matches the paths returned by
+/// [`crate::tree::TreeDragDropResponse::Completed`].
+///
+/// # Example
+///
+/// ```rust
+/// use egui_dnd::tree::DragableTreeItem;
+/// use egui_dnd::utils::move_tree_item;
+///
+/// #[derive(Hash, Debug, PartialEq)]
+/// struct Node(&'static str, Option<Vec<Node>>);
+///
+/// impl DragableTreeItem for Node {
+///     fn children(&self) -> Option<&[Self]> {
+///         self.1.as_deref()
+///     }
+///     fn children_mut(&mut self) -> Option<&mut Vec<Self>> {
+///         self.1.as_mut()
+///     }
+/// }
+///
+/// let mut tree = vec![Node("a", None), Node("b", Some(vec![Node("c", None)]))];
+/// // move "a" to the end of the children of "b"
+/// move_tree_item(&[0], &[1, 1], &mut tree).unwrap();
+/// assert_eq!(tree, [Node("b", Some(vec![Node("c", None), Node("a", None)]))]);
+/// // move "c" after "b"
+/// move_tree_item(&[0, 0], &[1], &mut tree).unwrap();
+/// assert_eq!(tree, [Node("b", Some(vec![Node("a", None)])), Node("c", None)]);
+/// ```
+///
+/// Returns an error if either path doesn't exist in the tree or if `target` is inside the source
+/// node.
+pub fn move_tree_item<T: DragableTreeItem>(
+    source: &[usize],
+    target: &[usize],
+    roots: &mut Vec<T>,
+) -> Result<(), MoveTreeItemError> {
+    let invalid_paths = || MoveTreeItemError::InvalidPaths {
+        source: source.to_vec(),
+        target: target.to_vec(),
+    };
+    let (&source_idx, source_parent) = source.split_last().ok_or_else(invalid_paths)?;
+    let (&target_idx, target_parent) = target.split_last().ok_or_else(invalid_paths)?;
+
+    if target.len() > source.len() && target.starts_with(source) {
+        return Err(MoveTreeItemError::TargetInsideSource {
+            source: source.to_vec(),
+            target: target.to_vec(),
+        });
+    }
+    let source_len = tree_children(roots, source_parent).map_or(0, |children| children.len());
+    let target_len = tree_children(roots, target_parent).map(|children| children.len());
+    if source_idx >= source_len || target_len.is_none_or(|len| target_idx > len) {
+        return Err(invalid_paths());
+    }
+
+    let source_children = tree_children(roots, source_parent).ok_or_else(invalid_paths)?;
+    let node = source_children.remove(source_idx);
+
+    // account for the source node being removed from in front of the target
+    let mut target = target.to_vec();
+    let depth = source_parent.len();
+    if target.len() > depth && target.starts_with(source_parent) && target[depth] > source_idx {
+        target[depth] -= 1;
+    }
+    let (&target_idx, target_parent) = target.split_last().ok_or_else(invalid_paths)?;
+    let target_children = tree_children(roots, target_parent).ok_or_else(invalid_paths)?;
+    target_children.insert(target_idx, node);
+    Ok(())
+}
+
+/// Returns the children of the node at `path`, or `roots` if `path` is empty.
+fn tree_children<'a, T: DragableTreeItem>(
+    roots: &'a mut Vec<T>,
+    path: &[usize],
+) -> Option<&'a mut Vec<T>> {
+    let mut children = roots;
+    for &idx in path {
+        children = children.get_mut(idx)?.children_mut()?;
+    }
+    Some(children)
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum ShiftSliceError {
     InvalidIndices {
@@ -65,3 +292,65 @@ impl std::fmt::Display for ShiftSliceError {
     }
 }
 impl std::error::Error for ShiftSliceError {}
+
+#[derive(Debug, Clone, Copy)]
+pub enum TransferItemError {
+    InvalidIndices {
+        source_idx: usize,
+        target_idx: usize,
+        source_len: usize,
+        target_len: usize,
+    },
+}
+impl std::fmt::Display for TransferItemError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidIndices {
+                source_idx,
+                target_idx,
+                source_len,
+                target_len,
+            } => {
+                write!(
+                    f,
+                    "Failed to move item from index {} to index {}. Source has {} elements, target has {} elements",
+                    source_idx, target_idx, source_len, target_len
+                )
+            }
+        }
+    }
+}
+impl std::error::Error for TransferItemError {}
+
+#[derive(Debug, Clone)]
+pub enum MoveTreeItemError {
+    InvalidPaths {
+        source: Vec<usize>,
+        target: Vec<usize>,
+    },
+    TargetInsideSource {
+        source: Vec<usize>,
+        target: Vec<usize>,
+    },
+}
+impl std::fmt::Display for MoveTreeItemError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidPaths { source, target } => {
+                write!(
+                    f,
+                    "Failed to move node from path {:?} to path {:?}. One of the paths doesn't exist",
+                    source, target
+                )
+            }
+            Self::TargetInsideSource { source, target } => {
+                write!(
+                    f,
+                    "Failed to move node from path {:?} to path {:?}. Can't move a node inside itself",
+                    source, target
+                )
+            }
+        }
+    }
+}
+impl std::error::Error for MoveTreeItemError {}
@@ -0,0 +1,709 @@
+use crate::{
+    handle::DragHandle, paint_swap_outline, style::DropIndicator, DragDropResponse, DragDropUi,
+    DragIndices, DragableItem, PlaceholderItem,
+};
+use egui::{
+    self, style::ScrollAnimation, Context, Id, Key, Rect, ScrollArea, Shape, Stroke, Ui, UiBuilder,
+    Vec2,
+};
+use std::ops::Range;
+
+/// Heights of the rows of a virtual list
+enum RowHeights<'a, T> {
+    /// Every row is this tall
+    Fixed(f32),
+    /// The height of the row of each item, see [`DragDropUi::virtual_list_ui_with_heights`]
+    Item(&'a dyn Fn(usize, &T) -> f32),
+}
+
+impl DragDropUi {
+    /// Draws `items` like [`DragDropUi::list_ui`], but inside `scroll_area` and only the rows that are
+    /// currently visible, so that lists of tens of thousands of items stay fast. Like
+    /// [`ScrollArea::show_rows`], every item has to be `row_height` tall (without the item spacing),
+    /// e.g. `ui.spacing().interact_size.y` for a row of buttons. Rows of different heights can be
+    /// drawn with [`DragDropUi::virtual_list_ui_with_heights`].
+    ///
+    /// The drop target is determined from the row positions rather than the drawn items, so items
+    /// can also be dropped at rows that are only reached by auto scrolling. Items can't be dragged
+    /// between virtual lists and [`DragDropUi::multi_select`] isn't supported.
+    pub fn virtual_list_ui<T: DragableItem>(
+        &mut self,
+        context: &Context,
+        ui: &mut Ui,
+        scroll_area: ScrollArea,
+        row_height: f32,
+        items: &[T],
+        mut item_ui: impl FnMut(&mut Ui, DragHandle, usize, &T),
+    ) -> DragDropResponse {
+        let row_heights = RowHeights::Fixed(row_height);
+        self.show_virtual_rows(context, ui, scroll_area, row_heights, items, &mut item_ui)
+    }
+
+    /// Draws `items` like [`DragDropUi::virtual_list_ui`], with rows as tall as `row_height(index,
+    /// item)` returns for the item drawn in them (without the item spacing). The heights of all
+    /// items are needed every frame to position the visible rows, so `row_height` should be cheap,
+    /// e.g. a lookup of heights measured or computed beforehand.
+    ///
+    /// ```rust,no_run
+    /// # use egui_dnd::DragDropUi;
+    /// # fn list_ui(ui: &mut egui::Ui, dnd: &mut DragDropUi, notes: &[Vec<String>]) {
+    /// let ctx = ui.ctx().clone();
+    /// let line_height = ui.text_style_height(&egui::TextStyle::Body);
+    /// let response = dnd.virtual_list_ui_with_heights(
+    ///     &ctx,
+    ///     ui,
+    ///     egui::ScrollArea::vertical(),
+    ///     |_index, lines| lines.len() as f32 * line_height,
+    ///     notes,
+    ///     |ui, handle, _index, lines| {
+    ///         handle.ui(ui, lines, |ui| {
+    ///             ui.label(lines.join("\n"));
+    ///         });
+    ///     },
+    /// );
+    /// # }
+    /// ```
+    pub fn virtual_list_ui_with_heights<T: DragableItem>(
+        &mut self,
+        context: &Context,
+        ui: &mut Ui,
+        scroll_area: ScrollArea,
+        row_height: impl Fn(usize, &T) -> f32,
+        items: &[T],
+        mut item_ui: impl FnMut(&mut Ui, DragHandle, usize, &T),
+    ) -> DragDropResponse {
+        let row_heights = RowHeights::Item(&row_height);
+        self.show_virtual_rows(context, ui, scroll_area, row_heights, items, &mut item_ui)
+    }
+
+    fn show_virtual_rows<T: DragableItem>(
+        &mut self,
+        context: &Context,
+        ui: &mut Ui,
+        scroll_area: ScrollArea,
+        row_heights: RowHeights<T>,
+        items: &[T],
+        item_ui: &mut impl FnMut(&mut Ui, DragHandle, usize, &T),
+    ) -> DragDropResponse {
+        if let Some(drag_indices) = self.drag_indices {
+            if drag_indices.source >= items.len() || drag_indices.target > items.len() {
+                // current drag indices are busted!
+                self.reset_drag();
+            }
+        }
+
+        scroll_area
+            .show_viewport(ui, |ui, viewport| {
+                self.draw_virtual_rows(context, ui, viewport, row_heights, items, item_ui)
+            })
+            .inner
+    }
+
+    /// Draws the rows of `items` within `viewport` to the content `ui` of the scroll area and returns
+    /// the dragging response.
+    fn draw_virtual_rows<T: DragableItem>(
+        &mut self,
+        context: &Context,
+        ui: &mut Ui,
+        viewport: Rect,
+        row_heights: RowHeights<T>,
+        items: &[T],
+        item_ui: &mut impl FnMut(&mut Ui, DragHandle, usize, &T),
+    ) -> DragDropResponse {
+        let spacing = ui.spacing().item_spacing.y;
+        let items_len = items.len();
+
+        // keep animating items for a moment after dragging ended, see `animate_item`
+        if self.drag_indices.is_some() {
+            self.last_drag_time = ui.input(|i| i.time);
+        }
+
+        // the rows are shifted according to previous hover state unless the target is shown with a
+        // line
+        let reflow = self.drop_indicator == DropIndicator::Reflow;
+        let swap = self.swapping();
+        let preview = self.drag_indices.filter(|_| reflow);
+        // a copy is previewed in an extra row at its target, see `DragDropUi::copy_preview`
+        let copy_preview = preview.filter(|_| self.copy_preview.is_some());
+        let copy_slot = copy_preview.map(|drag_indices| drag_indices.target);
+        let row_index = |row| match copy_preview {
+            Some(drag_indices) => copied_index(row, drag_indices),
+            None if swap => swapped_index(row, preview),
+            None => shifted_index(row, preview),
+        };
+        let rows_len = items_len + usize::from(copy_slot.is_some());
+        let rows = match row_heights {
+            RowHeights::Fixed(height) => RowLayout::Uniform {
+                height,
+                spacing,
+                len: rows_len,
+            },
+            RowHeights::Item(row_height) => RowLayout::varying(spacing, rows_len, |row| {
+                let idx = row_index(row);
+                row_height(idx, &items[idx])
+            }),
+        };
+        ui.set_height(rows.content_height());
+        let content_rect = ui.max_rect();
+        let visible_rows = rows.visible_rows(viewport);
+
+        // draw the visible rows
+        let mut focused_item = None;
+        let mut dragged_row_visible = false;
+        for row in visible_rows.clone() {
+            let idx = row_index(row);
+            let item = &items[idx];
+
+            // start each row where the row positions expect it, even if the item above is smaller
+            let missing = content_rect.top() + rows.top(row) - ui.cursor().top();
+            if missing > 0.0 {
+                ui.add_space(missing);
+            }
+
+            // leave the row of the copy empty
+            if copy_slot == Some(row) {
+                let size = Vec2::new(ui.available_width(), rows.height(row));
+                let (_id, rect) = ui.allocate_space(size);
+                let placeholder = PlaceholderItem::Item {
+                    index: idx,
+                    item_id: item.drag_id(),
+                };
+                self.draw_custom_placeholder(ui, placeholder, rect);
+                continue;
+            }
+
+            // highlight the item picked up with the keyboard. this is also the first shape of the
+            // item, see `animate_item`
+            let keyboard_background = ui.painter().add(Shape::Noop);
+            let draggable = self.can_drag_item(idx);
+            let rect = self.draw_item(
+                ui,
+                item.drag_id(),
+                Some(idx),
+                false,
+                draggable,
+                |ui, handle| {
+                    item_ui(ui, handle, idx, item);
+                },
+            );
+            if self.keyboard_drag == Some(item.drag_id()) {
+                let shape = epaint::RectShape::filled(
+                    rect.expand(2.0),
+                    ui.visuals().widgets.active.rounding,
+                    ui.visuals().selection.bg_fill.gamma_multiply(0.5),
+                );
+                ui.painter().set(keyboard_background, shape);
+            }
+
+            // check if this entry is being dragged
+            if context.is_being_dragged(item.drag_id()) {
+                self.set_source_index(idx);
+                dragged_row_visible = true;
+            } else {
+                self.animate_item(ui, item.drag_id(), keyboard_background, rect.min);
+            }
+
+            if context.memory(|m| m.has_focus(item.drag_id())) {
+                focused_item = Some((idx, item.drag_id()));
+            }
+        }
+
+        // the row of the dragged item may have been scrolled out of view
+        let dragged_idx = self
+            .drag_indices
+            .map(|drag_indices| drag_indices.source)
+            .filter(|idx| context.is_being_dragged(items[*idx].drag_id()));
+        if let (Some(idx), false) = (dragged_idx, dragged_row_visible) {
+            let item = &items[idx];
+            self.draw_floating_item(ui, item.drag_id(), |ui, handle| {
+                item_ui(ui, handle, idx, item);
+            });
+        }
+
+        // without reflow the item picked up with the keyboard stays in its row, which may have been
+        // scrolled out of view. it's still drawn there, so that it keeps the focus.
+        if let (Some(drag_indices), Some(grabbed_id)) = (self.drag_indices, self.keyboard_drag) {
+            let idx = drag_indices.source;
+            if !reflow && !visible_rows.contains(&idx) && idx < items_len {
+                let item = &items[idx];
+                let row_rect = Rect::from_min_size(
+                    content_rect.min + Vec2::new(0.0, rows.top(idx)),
+                    Vec2::new(content_rect.width(), rows.height(idx)),
+                );
+                let mut row_ui = ui.new_child(UiBuilder::new().max_rect(row_rect));
+                self.draw_item(
+                    &mut row_ui,
+                    grabbed_id,
+                    Some(idx),
+                    false,
+                    true,
+                    |ui, handle| {
+                        item_ui(ui, handle, idx, item);
+                    },
+                );
+                if context.memory(|m| m.has_focus(grabbed_id)) {
+                    focused_item = Some((idx, grabbed_id));
+                }
+            }
+        }
+
+        self.update_row_drag(
+            ui,
+            content_rect,
+            &rows,
+            focused_item,
+            dragged_idx.map(|idx| (idx, items[idx].drag_id())),
+        )
+    }
+
+    /// Updates the drag of a list laid out in `rows` after its visible rows are drawn, and returns
+    /// the dragging response. The item picked up with the keyboard is moved and kept in view, and
+    /// the target is determined from the row positions. `content_rect` is where all rows would be,
+    /// `focused_item` is the index and id of the item with keyboard focus and `dragged` the one of
+    /// the item dragged with the pointer, which may have been scrolled out of view. Used by
+    /// [`DragDropUi::virtual_list_ui`] and the table integration.
+    pub(crate) fn update_row_drag(
+        &mut self,
+        ui: &Ui,
+        content_rect: Rect,
+        rows: &RowLayout,
+        focused_item: Option<(usize, Id)>,
+        dragged: Option<(usize, Id)>,
+    ) -> DragDropResponse {
+        let context = ui.ctx();
+        let reflow = self.drop_indicator == DropIndicator::Reflow;
+        // rows as they are drawn this frame, with an extra row for a copy previewed at its target
+        let preview = self.drag_indices.filter(|_| reflow);
+        let copy_slot = preview
+            .filter(|_| self.copy_preview.is_some())
+            .map(|drag_indices| drag_indices.target);
+        let items_len = rows.len() - usize::from(copy_slot.is_some());
+        let row_rect = |row: usize| {
+            Rect::from_min_size(
+                content_rect.min + Vec2::new(0.0, rows.top(row)),
+                Vec2::new(content_rect.width(), rows.height(row)),
+            )
+        };
+        let swap = self.swapping();
+        let dragged_idx = dragged.map(|(idx, _)| idx);
+
+        // share the dragged item with drop zones
+        if let Some((idx, id)) = dragged {
+            self.share_with_drop_zones(context, idx, id);
+        }
+
+        // move the item picked up with the keyboard and keep it in view
+        let keyboard_response = self.update_keyboard_drag(ui, focused_item, items_len);
+        if let (Some(drag_indices), Some(_)) = (self.drag_indices, self.keyboard_drag) {
+            let row = if swap {
+                drag_indices.target
+            } else if reflow {
+                shifted_row(drag_indices)
+            } else {
+                drag_indices.target.min(items_len - 1)
+            };
+            ui.scroll_to_rect_animation(row_rect(row), None, ScrollAnimation::none());
+        }
+
+        // scroll towards the rows hidden beyond the edges of the scroll area
+        let released = ui.input(|i| i.pointer.any_released());
+        if self.drag_indices.is_some() && self.keyboard_drag.is_none() && !released {
+            self.auto_scroll(ui, content_rect);
+        }
+
+        // determine target index from the row (or the gap between rows for
+        // `DropIndicator::Line`) the dragged item is closest to. when swapping it is the row under
+        // the pointer.
+        let list_hovered_over = ui.rect_contains_pointer(ui.clip_rect());
+        let pointer_pos = ui.input(|i| i.pointer.interact_pos());
+        let mut hovering_idx = None;
+        if let (Some(drag_indices), Some(pointer_pos)) = (self.drag_indices, pointer_pos) {
+            let dragged_top = pointer_pos.y + self.drag_delta.unwrap_or_default().y;
+            let closest_row = rows.closest_top(dragged_top - content_rect.top());
+            let target = if swap {
+                rows.row_at(pointer_pos.y - content_rect.top())
+            } else if let (true, Some(_)) = (reflow, copy_slot) {
+                // the copy is inserted in front of the item now drawn in this row
+                closest_row.min(items_len)
+            } else if reflow {
+                let row = closest_row.min(items_len.saturating_sub(1));
+                if row > drag_indices.source {
+                    row + 1
+                } else {
+                    row
+                }
+            } else {
+                closest_row
+            };
+            // skip targets the dragged item can't be dropped at, which are all targets in
+            // source-only lists
+            hovering_idx =
+                Some(target).filter(|target| !self.source_only && self.can_drop_at(*target));
+        }
+        let drop_target = hovering_idx.filter(|_| list_hovered_over);
+        if let (Some(drag_indices), None) = (&mut self.drag_indices, self.keyboard_drag) {
+            // no row being hovered over -> no target
+            drag_indices.target = drop_target.unwrap_or(drag_indices.source);
+            self.set_drop_not_allowed(ui, list_hovered_over && hovering_idx.is_none());
+        }
+
+        // fade the dragged item while releasing it would remove it
+        if let Some(idx) = dragged_idx {
+            self.update_drop_removes(context, idx, !list_hovered_over);
+            self.update_copying(ui, drop_target.is_some());
+
+            // preview the copy at its target, as tall as the row of the dragged item
+            let dragged_row = copy_slot.or(preview.map(shifted_row));
+            self.copy_preview = dragged_row
+                .filter(|_| self.copying && drop_target.is_some() && !released)
+                .map(|row| row_rect(row).size());
+        }
+
+        // show where the dragged item will be inserted
+        if let (DropIndicator::Line { .. }, Some(drag_indices)) =
+            (self.drop_indicator, self.drag_indices)
+        {
+            if drop_target.is_some() || self.keyboard_drag.is_some() {
+                if !swap {
+                    let target_top = content_rect.top() + rows.top(drag_indices.target);
+                    let y = target_top - rows.spacing() / 2.0;
+                    let stroke = Stroke::new(2.0, ui.visuals().selection.stroke.color);
+                    ui.painter().hline(content_rect.x_range(), y, stroke);
+                } else if drag_indices.target != drag_indices.source {
+                    paint_swap_outline(ui, row_rect(drag_indices.target));
+                }
+            }
+        }
+
+        // return dragging state
+        if let Some(keyboard_response) = keyboard_response {
+            return keyboard_response;
+        }
+        if let Some(drag_indices) = self.drag_indices {
+            let escape_pressed = ui.input(|i| i.key_pressed(Key::Escape));
+            let outside_list = drop_target.is_none();
+            let finished = self.finish_pointer_drag(
+                context,
+                drag_indices,
+                released,
+                escape_pressed,
+                outside_list,
+            );
+            // dragging in progress
+            return finished.unwrap_or(DragDropResponse::CurrentDrag(drag_indices));
+        }
+        DragDropResponse::NoDrag
+    }
+}
+
+/// Vertical positions of the rows of a virtual list or table, in the order they are drawn. Offsets
+/// are measured from the top of the first row.
+pub(crate) enum RowLayout {
+    /// All `len` rows are `height` tall
+    Uniform {
+        height: f32,
+        spacing: f32,
+        len: usize,
+    },
+    /// `tops[row]` is the offset of each row, followed by the offset a row after the last one would
+    /// have
+    Varying { tops: Vec<f32>, spacing: f32 },
+}
+
+impl RowLayout {
+    /// Lays out `len` rows as tall as `row_height(row)` returns
+    fn varying(spacing: f32, len: usize, mut row_height: impl FnMut(usize) -> f32) -> Self {
+        let mut tops = Vec::with_capacity(len + 1);
+        let mut top = 0.0;
+        tops.push(top);
+        for row in 0..len {
+            top += row_height(row) + spacing;
+            tops.push(top);
+        }
+        Self::Varying { tops, spacing }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Self::Uniform { len, .. } => *len,
+            Self::Varying { tops, .. } => tops.len() - 1,
+        }
+    }
+
+    pub(crate) fn spacing(&self) -> f32 {
+        match self {
+            Self::Uniform { spacing, .. } | Self::Varying { spacing, .. } => *spacing,
+        }
+    }
+
+    /// Offset of `row`, which may also be the row after the last one
+    pub(crate) fn top(&self, row: usize) -> f32 {
+        match self {
+            Self::Uniform {
+                height, spacing, ..
+            } => row as f32 * (height + spacing),
+            Self::Varying { tops, .. } => tops[row],
+        }
+    }
+
+    pub(crate) fn height(&self, row: usize) -> f32 {
+        match self {
+            Self::Uniform { height, .. } => *height,
+            Self::Varying { tops, spacing } => tops[row + 1] - tops[row] - spacing,
+        }
+    }
+
+    /// Height of all rows including the spacing between them
+    pub(crate) fn content_height(&self) -> f32 {
+        (self.top(self.len()) - self.spacing()).max(0.0)
+    }
+
+    /// The row at the offset `y`, or the first or last row if `y` is beyond them
+    fn row_at(&self, y: f32) -> usize {
+        let row = match self {
+            Self::Uniform {
+                height, spacing, ..
+            } => (y / (height + spacing)).floor() as usize,
+            Self::Varying { tops, .. } => tops.partition_point(|top| *top <= y).saturating_sub(1),
+        };
+        row.min(self.len().saturating_sub(1))
+    }
+
+    /// The row whose top is closest to the offset `y`, which may also be the row after the last one
+    fn closest_top(&self, y: f32) -> usize {
+        match self {
+            Self::Uniform {
+                height,
+                spacing,
+                len,
+            } => (y / (height + spacing)).round().clamp(0.0, *len as f32) as usize,
+            Self::Varying { tops, .. } => {
+                let next = tops.partition_point(|top| *top < y).min(tops.len() - 1);
+                if next > 0 && y - tops[next - 1] < tops[next] - y {
+                    next - 1
+                } else {
+                    next
+                }
+            }
+        }
+    }
+
+    /// Rows within the `viewport` of the scroll area, same as [`ScrollArea::show_rows`]
+    fn visible_rows(&self, viewport: Rect) -> Range<usize> {
+        let len = self.len();
+        let (mut min_row, mut max_row) = match self {
+            Self::Uniform {
+                height, spacing, ..
+            } => {
+                let row_step = height + spacing;
+                (
+                    (viewport.min.y / row_step).floor() as usize,
+                    (viewport.max.y / row_step).ceil() as usize + 1,
+                )
+            }
+            Self::Varying { .. } => (self.row_at(viewport.min.y), self.row_at(viewport.max.y) + 2),
+        };
+        if max_row > len {
+            let diff = max_row.saturating_sub(min_row);
+            max_row = len;
+            min_row = len.saturating_sub(diff);
+        }
+        min_row..max_row
+    }
+}
+
+/// Returns the row the dragged item is previewed at
+fn shifted_row(drag_indices: DragIndices) -> usize {
+    if drag_indices.target > drag_indices.source {
+        drag_indices.target - 1
+    } else {
+        drag_indices.target
+    }
+}
+
+/// Returns the index of the item drawn at `row` while the dragged item is previewed in the row of the
+/// item it will be swapped with, see [`crate::DropBehavior::Swap`].
+pub(crate) fn swapped_index(row: usize, drag_indices: Option<DragIndices>) -> usize {
+    match drag_indices {
+        Some(drag_indices) if row == drag_indices.target => drag_indices.source,
+        Some(drag_indices) if row == drag_indices.source => drag_indices.target,
+        _ => row,
+    }
+}
+
+/// Returns the index of the item drawn at `row` while a copy of the dragged item is previewed in an
+/// extra row at its target, i.e. the index the row would have if the copy was inserted with
+/// [`Vec::insert`]. The extra row returns the index of the dragged item.
+pub(crate) fn copied_index(row: usize, drag_indices: DragIndices) -> usize {
+    if row < drag_indices.target {
+        row
+    } else if row == drag_indices.target {
+        drag_indices.source
+    } else {
+        row - 1
+    }
+}
+
+/// Returns the index of the item drawn at `row` while the dragged item is previewed at its target,
+/// i.e. the index the row would have in a list shifted with [`crate::utils::shift_slice`].
+pub(crate) fn shifted_index(row: usize, drag_indices: Option<DragIndices>) -> usize {
+    let Some(drag_indices) = drag_indices else {
+        return row;
+    };
+    let preview_row = shifted_row(drag_indices);
+    if row == preview_row {
+        return drag_indices.source;
+    }
+
+    // index among the items that aren't dragged
+    let other_idx = if row < preview_row { row } else { row - 1 };
+    if other_idx < drag_indices.source {
+        other_idx
+    } else {
+        other_idx + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{shift_slice, swap_slice};
+
+    const LEN: usize = 5;
+
+    /// The indices of a list of `LEN` items, in the order the rows draw them
+    fn rows(index: impl Fn(usize) -> usize) -> Vec<usize> {
+        (0..LEN).map(index).collect()
+    }
+
+    #[test]
+    fn shifted_index_matches_shift_slice() {
+        for source in 0..LEN {
+            for target in 0..=LEN {
+                let mut shifted = rows(|idx| idx);
+                shift_slice(source, target, &mut shifted).unwrap();
+                let drag_indices = Some(DragIndices { source, target });
+                assert_eq!(
+                    rows(|row| shifted_index(row, drag_indices)),
+                    shifted,
+                    "source {source}, target {target}"
+                );
+            }
+        }
+        assert_eq!(rows(|row| shifted_index(row, None)), rows(|idx| idx));
+    }
+
+    #[test]
+    fn swapped_index_matches_swap_slice() {
+        for source in 0..LEN {
+            for target in 0..LEN {
+                let mut swapped = rows(|idx| idx);
+                swap_slice(source, target, &mut swapped).unwrap();
+                let drag_indices = Some(DragIndices { source, target });
+                assert_eq!(
+                    rows(|row| swapped_index(row, drag_indices)),
+                    swapped,
+                    "source {source}, target {target}"
+                );
+            }
+        }
+        assert_eq!(rows(|row| swapped_index(row, None)), rows(|idx| idx));
+    }
+
+    #[test]
+    fn copied_index_matches_insert() {
+        for source in 0..LEN {
+            for target in 0..=LEN {
+                let mut copied = rows(|idx| idx);
+                copied.insert(target, source);
+                let drag_indices = DragIndices { source, target };
+                let copied_rows = (0..=LEN)
+                    .map(|row| copied_index(row, drag_indices))
+                    .collect::<Vec<_>>();
+                assert_eq!(copied_rows, copied, "source {source}, target {target}");
+            }
+        }
+    }
+
+    /// Rows of the heights 10, 30 and 20 with 2 points of spacing
+    fn varying() -> RowLayout {
+        RowLayout::varying(2.0, 3, |row| [10.0, 30.0, 20.0][row])
+    }
+
+    #[test]
+    fn varying_rows_are_stacked() {
+        let rows = varying();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows.top(0), 0.0);
+        assert_eq!(rows.top(1), 12.0);
+        assert_eq!(rows.top(2), 44.0);
+        assert_eq!(rows.top(3), 66.0);
+        assert_eq!(rows.height(1), 30.0);
+        assert_eq!(rows.content_height(), 64.0);
+    }
+
+    #[test]
+    fn varying_rows_are_hit_tested_by_offset() {
+        let rows = varying();
+        let at = [
+            (-5.0, 0),
+            (0.0, 0),
+            (11.0, 0),
+            (12.0, 1),
+            (43.0, 1),
+            (44.0, 2),
+            (100.0, 2),
+        ];
+        for (y, row) in at {
+            assert_eq!(rows.row_at(y), row, "row at {y}");
+        }
+        let closest = [
+            (-5.0, 0),
+            (5.0, 0),
+            (7.0, 1),
+            (27.0, 1),
+            (29.0, 2),
+            (56.0, 3),
+            (100.0, 3),
+        ];
+        for (y, row) in closest {
+            assert_eq!(rows.closest_top(y), row, "closest top to {y}");
+        }
+    }
+
+    #[test]
+    fn uniform_and_varying_rows_of_the_same_height_match() {
+        let uniform = RowLayout::Uniform {
+            height: 10.0,
+            spacing: 2.0,
+            len: LEN,
+        };
+        let varying = RowLayout::varying(2.0, LEN, |_| 10.0);
+        assert_eq!(uniform.content_height(), varying.content_height());
+        for row in 0..=LEN {
+            assert_eq!(uniform.top(row), varying.top(row));
+        }
+        for y in (-10..70).map(|y| y as f32 + 0.5) {
+            assert_eq!(uniform.row_at(y), varying.row_at(y), "row at {y}");
+            assert_eq!(
+                uniform.closest_top(y),
+                varying.closest_top(y),
+                "closest top to {y}"
+            );
+        }
+        let viewport =
+            |min_y: f32| Rect::from_min_size(egui::pos2(0.0, min_y), Vec2::new(10.0, 25.0));
+        for min_y in [0.0, 13.0] {
+            assert_eq!(
+                uniform.visible_rows(viewport(min_y)),
+                varying.visible_rows(viewport(min_y)),
+                "viewport at {min_y}"
+            );
+        }
+        // at the end of the list, every row overlapping the viewport is drawn
+        for rows in [uniform, varying] {
+            let visible = rows.visible_rows(viewport(40.0));
+            assert!(visible.contains(&3) && visible.end == LEN, "{visible:?}");
+        }
+    }
+}